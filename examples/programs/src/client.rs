@@ -0,0 +1,173 @@
+//! Off-chain helpers that build instructions for the examples.
+//!
+//! Each builder encodes the instruction data exactly as the matching on-chain
+//! `process_instruction` parses it, and lists the accounts in the documented order.
+
+use crate::common::encode::IxData;
+
+use spl_token::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::{Pubkey, PubkeyError, MAX_SEED_LEN},
+    system_program,
+};
+
+/// Builds an instruction for the `mint_to_checked` example.
+///
+/// ### Data:
+/// `[amount: 8][decimals: 1][bump: 1]`
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint account.
+/// 1. `[WRITE]` The account to mint tokens to.
+/// 2. `[SIGNER]` The mint's minting authority.
+/// 3. `[]` The token program.
+pub fn build_mint_to_checked_ix(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+    bump: u8,
+) -> Instruction {
+    let data = IxData::new()
+        .push_u64_le(amount)
+        .push_u8(decimals)
+        .push_u8(bump)
+        .into_vec();
+
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+    )
+}
+
+/// Builds an instruction for the `create_account_with_seed` example.
+///
+/// The new account's address must be derived with
+/// `Pubkey::create_with_seed(base, seed, owner)`.
+///
+/// ### Data:
+/// `[seed_len: 1][seed: seed_len][lamports: 8][space: 8][owner: 32][bump: 1]`
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The funding account.
+/// 1. `[WRITE]` The new account to be created.
+/// 2. `[SIGNER]` The base account used to derive the new account.
+/// 3. `[]` The system program.
+///
+/// ### Errors:
+/// - `PubkeyError::MaxSeedLengthExceeded` if the seed is longer than `MAX_SEED_LEN` bytes.
+#[allow(clippy::too_many_arguments)]
+pub fn build_create_account_with_seed_ix(
+    program_id: &Pubkey,
+    funding: &Pubkey,
+    new_account: &Pubkey,
+    base: &Pubkey,
+    seed: &str,
+    lamports: u64,
+    space: u64,
+    owner: &Pubkey,
+    bump: u8,
+) -> Result<Instruction, PubkeyError> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(PubkeyError::MaxSeedLengthExceeded);
+    }
+
+    let data = IxData::new()
+        .push_str_len_prefixed(seed)
+        .push_u64_le(lamports)
+        .push_u64_le(space)
+        .push_pubkey(owner)
+        .push_u8(bump)
+        .into_vec();
+
+    Ok(Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*funding, true),
+            AccountMeta::new(*new_account, false),
+            AccountMeta::new_readonly(*base, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the `mint_to_checked` data layout.
+    #[test]
+    fn build_mint_to_checked_ix_layout_test() {
+        let program_id = Pubkey::new_unique();
+        let instruction = build_mint_to_checked_ix(
+            &program_id,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0x0102_0304_0506_0708,
+            9,
+            254,
+        );
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(
+            instruction.data,
+            vec![0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 9, 254]
+        );
+        assert_eq!(instruction.accounts.len(), 4);
+        assert!(instruction.accounts[2].is_signer);
+        assert_eq!(instruction.accounts[3].pubkey, spl_token::id());
+    }
+
+    /// Tests the `create_account_with_seed` data layout and seed length validation.
+    #[test]
+    fn build_create_account_with_seed_ix_layout_test() {
+        let owner = Pubkey::new_unique();
+        let instruction = build_create_account_with_seed_ix(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            "abc",
+            7,
+            9,
+            &owner,
+            253,
+        )
+        .unwrap();
+
+        let mut expected = vec![3, b'a', b'b', b'c'];
+        expected.extend_from_slice(&7_u64.to_le_bytes());
+        expected.extend_from_slice(&9_u64.to_le_bytes());
+        expected.extend_from_slice(owner.as_ref());
+        expected.push(253);
+        assert_eq!(instruction.data, expected);
+        assert_eq!(instruction.accounts[3].pubkey, system_program::id());
+
+        // Seeds longer than `MAX_SEED_LEN` are rejected.
+        let long_seed = "a".repeat(MAX_SEED_LEN + 1);
+        assert_eq!(
+            build_create_account_with_seed_ix(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &long_seed,
+                0,
+                0,
+                &owner,
+                0,
+            ),
+            Err(PubkeyError::MaxSeedLengthExceeded)
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+/// Splits the accounts into `N` fixed accounts and the variable-length remainder.
+///
+/// Slice patterns (`[a, b, rest @ ..]`) do the same for a known `N`; this helper is useful
+/// when the fixed accounts are forwarded as a group, e.g. to a CPI that also takes a
+/// variable number of signers.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts passed to the instruction.
+///
+/// ### Returns:
+/// - `Result<(&[AccountInfo; N], &[AccountInfo]), ProgramError>`: The fixed accounts and
+///   the remaining ones, or `NotEnoughAccountKeys` if fewer than `N` accounts were passed.
+pub fn split_fixed_and_rest<const N: usize>(
+    accounts: &[AccountInfo],
+) -> Result<(&[AccountInfo; N], &[AccountInfo]), ProgramError> {
+    if accounts.len() < N {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let (fixed, rest) = accounts.split_at(N);
+
+    Ok((
+        fixed
+            .try_into()
+            .map_err(|_| ProgramError::NotEnoughAccountKeys)?,
+        rest,
+    ))
+}
+
+/// Ensures each account carries the signer and writable flags its role requires.
+///
+/// `expected` lists one `(signer, writable)` pair per account, in the documented order. A
+/// `true` flag is required; a `false` flag is not checked, so a writable account may still be
+/// passed where a read-only one is expected. Accounts beyond `expected` are not checked.
+/// Swapped accounts usually surface here, before any data is read.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts passed to the instruction.
+/// - `expected`: The required `(signer, writable)` flags of each account.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `NotEnoughAccountKeys` if fewer accounts than roles were
+///   passed, `MissingRequiredSignature` if a signer is missing, or `InvalidAccountData` if a
+///   writable account is read-only.
+pub fn require_roles(
+    accounts: &[AccountInfo],
+    expected: &[(bool, bool)],
+) -> Result<(), ProgramError> {
+    if accounts.len() < expected.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    check_roles(
+        accounts
+            .iter()
+            .map(|account| (account.is_signer(), account.is_writable())),
+        expected,
+    )
+}
+
+/// Checks `(is_signer, is_writable)` flags against the required roles.
+fn check_roles(
+    actual: impl Iterator<Item = (bool, bool)>,
+    expected: &[(bool, bool)],
+) -> Result<(), ProgramError> {
+    for ((is_signer, is_writable), &(signer, writable)) in actual.zip(expected) {
+        if signer && !is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if writable && !is_writable {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The roles of a token transfer: sender, recipient, authority and token program.
+    const TRANSFER_ROLES: [(bool, bool); 4] =
+        [(false, true), (false, true), (true, false), (false, false)];
+
+    /// Tests that accounts in the documented order are accepted.
+    #[test]
+    fn check_roles_correct_order_test() {
+        let actual = [(false, true), (false, true), (true, false), (false, false)];
+        assert_eq!(check_roles(actual.into_iter(), &TRANSFER_ROLES), Ok(()));
+
+        // Extra privileges are allowed.
+        let actual = [(false, true), (false, true), (true, true), (false, false)];
+        assert_eq!(check_roles(actual.into_iter(), &TRANSFER_ROLES), Ok(()));
+    }
+
+    /// Tests that swapping the signer with a non-signer is rejected.
+    #[test]
+    fn check_roles_swapped_signer_test() {
+        // The authority and the recipient are swapped.
+        let actual = [(false, true), (true, false), (false, true), (false, false)];
+        assert_eq!(
+            check_roles(actual.into_iter(), &TRANSFER_ROLES),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // The authority did not sign, the token program slot did.
+        let actual = [(false, true), (false, true), (false, false), (true, false)];
+        assert_eq!(
+            check_roles(actual.into_iter(), &TRANSFER_ROLES),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+}
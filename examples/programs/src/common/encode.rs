@@ -0,0 +1,110 @@
+//! Off-chain encoding of instruction data.
+
+/// Builds instruction data field by field, in the order the on-chain decoders read it.
+///
+/// ```ignore
+/// let data = IxData::new().push_u64_le(amount).push_u8(bump).into_vec();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IxData {
+    bytes: Vec<u8>,
+}
+
+impl IxData {
+    /// Creates empty instruction data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single byte.
+    pub fn push_u8(mut self, value: u8) -> Self {
+        self.bytes.push(value);
+        self
+    }
+
+    /// Appends a `u64` as 8 little-endian bytes.
+    pub fn push_u64_le(mut self, value: u64) -> Self {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends the 32 bytes of a public key.
+    pub fn push_pubkey(mut self, key: impl AsRef<[u8]>) -> Self {
+        debug_assert_eq!(key.as_ref().len(), 32);
+        self.bytes.extend_from_slice(key.as_ref());
+        self
+    }
+
+    /// Appends a string as `[len: u8][bytes: len]`, the layout read by `ix::read_seed`.
+    ///
+    /// `read_seed` only accepts ASCII, so seeds meant for it must not contain other characters.
+    ///
+    /// ### Panics:
+    /// - If the string is longer than 255 bytes, since its length would not fit the prefix.
+    pub fn push_str_len_prefixed(mut self, value: &str) -> Self {
+        let len = u8::try_from(value.len()).expect("string longer than 255 bytes");
+        self.bytes.push(len);
+        self.bytes.extend_from_slice(value.as_bytes());
+        self
+    }
+
+    /// Returns the encoded instruction data.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IxData;
+    use crate::common::ix::{read_pubkey, read_seed};
+
+    /// Tests that each field is appended in order with the expected width.
+    #[test]
+    fn ix_data_layout_test() {
+        let data = IxData::new()
+            .push_u8(7)
+            .push_u64_le(0x0102_0304_0506_0708)
+            .push_str_len_prefixed("ab")
+            .push_pubkey([9; 32])
+            .into_vec();
+
+        let mut expected = vec![7, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 2, b'a', b'b'];
+        expected.extend_from_slice(&[9; 32]);
+        assert_eq!(data, expected);
+
+        assert!(IxData::new().into_vec().is_empty());
+    }
+
+    /// Tests that encoded fields read back through the on-chain decoders.
+    #[test]
+    fn ix_data_matches_decoders_test() {
+        let key = [5; 32];
+        let data = IxData::new()
+            .push_u64_le(42)
+            .push_pubkey(key)
+            .push_str_len_prefixed("vault")
+            .push_u8(3)
+            .into_vec();
+
+        // Fixed-width values are read as little-endian, like the `unsafe` pointer reads.
+        assert_eq!(u64::from_le_bytes(data[..8].try_into().unwrap()), 42);
+        assert_eq!(read_pubkey(&data, 8), Ok(key));
+
+        // The seed reads back without swallowing the field that follows it.
+        assert_eq!(read_seed(&data, 40), Ok("vault"));
+        assert_eq!(data[40 + 1 + "vault".len()], 3);
+
+        // The longest string the prefix allows also reads back whole.
+        let longest = "s".repeat(255);
+        let data = IxData::new().push_str_len_prefixed(&longest).into_vec();
+        assert_eq!(read_seed(&data, 0), Ok(longest.as_str()));
+    }
+
+    /// Tests that strings too long for a one-byte length prefix are rejected.
+    #[test]
+    #[should_panic(expected = "string longer than 255 bytes")]
+    fn ix_data_long_string_test() {
+        let _ = IxData::new().push_str_len_prefixed(&"a".repeat(256));
+    }
+}
@@ -0,0 +1,88 @@
+use pinocchio::{msg, program_error::ProgramError, ProgramResult};
+
+/// Formats the line logged when an instruction fails.
+///
+/// ### Parameters:
+/// - `error`: The error returned by the instruction.
+///
+/// ### Returns:
+/// - `String`: The log line, e.g. `Error: InvalidInstructionData`.
+pub fn error_line(error: &ProgramError) -> String {
+    format!("Error: {error:?}")
+}
+
+/// Encodes a custom error code the way clients read it from return data.
+///
+/// Only `ProgramError::Custom` codes are encoded; builtin errors are already reported as
+/// distinct variants in the transaction result.
+///
+/// ### Parameters:
+/// - `error`: The error returned by the instruction.
+///
+/// ### Returns:
+/// - `Option<[u8; 4]>`: The little-endian custom code, or `None` for builtin errors.
+pub fn error_code_bytes(error: &ProgramError) -> Option<[u8; 4]> {
+    match error {
+        ProgramError::Custom(code) => Some(code.to_le_bytes()),
+        _ => None,
+    }
+}
+
+/// Runs an instruction and logs its error, if any, before returning it.
+///
+/// Errors otherwise only surface as codes in the transaction result; logging them
+/// makes failures readable in the program logs.
+///
+/// ### Parameters:
+/// - `inner`: The instruction to run.
+///
+/// ### Returns:
+/// - `ProgramResult`: The result of `inner`, unchanged.
+pub fn run(inner: impl FnOnce() -> ProgramResult) -> ProgramResult {
+    run_with_log(inner, |line| msg!(line))
+}
+
+/// Same as [`run`], with the log sink passed in so the output can be captured.
+fn run_with_log(inner: impl FnOnce() -> ProgramResult, mut log: impl FnMut(&str)) -> ProgramResult {
+    inner().inspect_err(|error| log(&error_line(error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that a failing instruction logs its error and returns it unchanged.
+    #[test]
+    fn run_logs_error_test() {
+        let mut logs = Vec::new();
+
+        let result = run_with_log(
+            || Err(ProgramError::InvalidInstructionData),
+            |line| logs.push(line.to_string()),
+        );
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+        assert_eq!(logs, vec!["Error: InvalidInstructionData".to_string()]);
+    }
+
+    /// Tests that a successful instruction logs nothing.
+    #[test]
+    fn run_success_test() {
+        let mut logs = Vec::new();
+
+        let result = run_with_log(|| Ok(()), |line| logs.push(line.to_string()));
+
+        assert_eq!(result, Ok(()));
+        assert!(logs.is_empty());
+    }
+
+    /// Tests that only custom errors are encoded, as little-endian codes.
+    #[test]
+    fn error_code_bytes_test() {
+        assert_eq!(
+            error_code_bytes(&ProgramError::Custom(0x0102_0304)),
+            Some([0x04, 0x03, 0x02, 0x01])
+        );
+        assert_eq!(error_code_bytes(&ProgramError::InvalidInstructionData), None);
+    }
+}
@@ -0,0 +1,47 @@
+use pinocchio::program_error::ProgramError;
+
+/// Custom errors returned by the examples.
+///
+/// Each variant is surfaced as `ProgramError::Custom`, using its discriminant as the code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ExampleError {
+    /// The instruction was invoked with a zero amount.
+    ZeroAmount = 0,
+    /// Two token accounts that must hold the same mint hold different mints.
+    MintMismatch = 1,
+    /// The requested amount is above the allowed maximum.
+    AmountExceedsCap = 2,
+    /// The source account holds fewer tokens than requested.
+    InsufficientFunds = 3,
+    /// The same account was passed in two positions that must differ.
+    DuplicateAccount = 4,
+    /// The mint has no freeze authority, so its accounts can never be frozen.
+    NoFreezeAuthority = 5,
+    /// The token account was expected to be frozen but is not.
+    AccountNotFrozen = 6,
+    /// The withdrawal would leave the account below its rent-exempt minimum.
+    ExceedsWithdrawable = 7,
+    /// The token account has no delegate to revoke.
+    NoDelegate = 8,
+    /// The mint decimals are above the supported maximum.
+    InvalidDecimals = 9,
+    /// The destination token account is frozen and cannot receive tokens.
+    DestinationFrozen = 10,
+    /// The transfer would leave the source below its required reserve.
+    ReserveViolated = 11,
+    /// The mint still has tokens in circulation.
+    SupplyNotZero = 12,
+    /// The mint has no close authority, so it can never be closed.
+    NoCloseAuthority = 13,
+    /// The recipient is not on the program's whitelist.
+    RecipientNotWhitelisted = 14,
+    /// The signer is not the mint authority stored in the mint.
+    MintAuthorityMismatch = 15,
+}
+
+impl From<ExampleError> for ProgramError {
+    fn from(error: ExampleError) -> Self {
+        ProgramError::Custom(error as u32)
+    }
+}
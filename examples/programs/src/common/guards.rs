@@ -0,0 +1,221 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, sysvars::rent::Rent,
+};
+
+use super::error::ExampleError;
+
+/// Ensures an amount is not zero.
+///
+/// Transferring, minting or burning zero tokens is a no-op that usually hides a client bug.
+///
+/// ### Parameters:
+/// - `amount`: The amount to validate.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `ExampleError::ZeroAmount` if the amount is zero.
+pub fn require_nonzero(amount: u64) -> Result<(), ProgramError> {
+    if amount == 0 {
+        return Err(ExampleError::ZeroAmount.into());
+    }
+
+    Ok(())
+}
+
+/// Ensures an account is owned by the given program.
+///
+/// State accounts must be owned by the program before their data is trusted or mutated.
+///
+/// ### Parameters:
+/// - `account`: The account to validate.
+/// - `program_id`: The program that must own the account.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidAccountOwner` if the account has another owner.
+pub fn require_owned_by_program(
+    account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<(), ProgramError> {
+    if !account.is_owned_by(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    Ok(())
+}
+
+/// Loads the data of a program-owned state account of exactly `LEN` bytes.
+///
+/// Ownership is checked before the data is trusted, and the exact length rules out a
+/// resized or foreign layout. The data is borrowed without holding a `Ref`, so the returned
+/// array must not be used across a mutable borrow of the account or a CPI that writes it.
+///
+/// ### Parameters:
+/// - `ai`: The state account.
+/// - `program_id`: The program that must own the account.
+///
+/// ### Returns:
+/// - `Result<&[u8; LEN], ProgramError>`: The account data, `InvalidAccountOwner` if the
+///   account has another owner, `AccountBorrowFailed` if it is mutably borrowed, or
+///   `InvalidAccountData` if its length is not `LEN`.
+pub fn load_state<'a, const LEN: usize>(
+    ai: &'a AccountInfo,
+    program_id: &Pubkey,
+) -> Result<&'a [u8; LEN], ProgramError> {
+    require_owned_by_program(ai, program_id)?;
+
+    // Ensure the data is not mutably borrowed elsewhere.
+    ai.check_borrow_data()?;
+
+    // SAFETY: no mutable borrow is outstanding, and callers must not hold the data across
+    // one (see above).
+    unsafe { ai.borrow_data_unchecked() }
+        .try_into()
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Loads the data of a program-owned state account of exactly `LEN` bytes for writing.
+///
+/// The mutable counterpart of [`load_state`], with the same checks. The data is borrowed
+/// without holding a `RefMut`, so the returned array must not be used across another borrow
+/// of the account or a CPI that reads or writes it.
+///
+/// ### Parameters:
+/// - `ai`: The state account.
+/// - `program_id`: The program that must own the account.
+///
+/// ### Returns:
+/// - `Result<&mut [u8; LEN], ProgramError>`: The account data, `InvalidAccountOwner` if the
+///   account has another owner, `AccountBorrowFailed` if it is already borrowed, or
+///   `InvalidAccountData` if its length is not `LEN`.
+pub fn load_state_mut<'a, const LEN: usize>(
+    ai: &'a AccountInfo,
+    program_id: &Pubkey,
+) -> Result<&'a mut [u8; LEN], ProgramError> {
+    require_owned_by_program(ai, program_id)?;
+
+    // Ensure the data is not borrowed elsewhere.
+    ai.check_borrow_mut_data()?;
+
+    // SAFETY: no borrow is outstanding, and callers must not hold the data across one (see
+    // above).
+    unsafe { ai.borrow_mut_data_unchecked() }
+        .try_into()
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Ensures an account has the expected key.
+///
+/// Use it for accounts with a well-known address, such as sysvars and programs, instead of
+/// `assert_eq!`, which aborts the program with an opaque panic.
+///
+/// ### Parameters:
+/// - `account`: The account to validate.
+/// - `expected`: The key the account must have.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidArgument` if the keys differ.
+pub fn require_key(account: &AccountInfo, expected: &Pubkey) -> Result<(), ProgramError> {
+    if account.key() != expected {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+/// The system program's address, `11111111111111111111111111111111`.
+pub const SYSTEM_PROGRAM_ID: Pubkey = [0; 32];
+
+/// Ensures an account is the system program.
+///
+/// System program CPIs are routed through the program account passed in; checking its key
+/// up front rejects a substituted program before anything is invoked.
+///
+/// ### Parameters:
+/// - `account`: The account to validate.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `IncorrectProgramId` if the account is another program.
+pub fn require_system_program(account: &AccountInfo) -> Result<(), ProgramError> {
+    if account.key() != &SYSTEM_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
+/// Computes the balances after moving `lamports` from one account to another.
+///
+/// Programs that edit lamports directly, rather than through the system program, must
+/// neither underflow the source nor overflow the destination.
+///
+/// ### Parameters:
+/// - `from`: The balance of the source account.
+/// - `to`: The balance of the destination account.
+/// - `lamports`: The lamports to move.
+///
+/// ### Returns:
+/// - `Result<(u64, u64), ProgramError>`: The new source and destination balances, or
+///   `ArithmeticOverflow` if either would wrap.
+pub fn checked_lamport_move(from: u64, to: u64, lamports: u64) -> Result<(u64, u64), ProgramError> {
+    let from = from
+        .checked_sub(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let to = to
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok((from, to))
+}
+
+/// Ensures an account holds enough lamports to be rent-exempt for its current size.
+///
+/// Call it after creating or resizing an account: the system program does not check that the
+/// requested lamports cover the requested space.
+///
+/// ### Parameters:
+/// - `account`: The account to validate.
+/// - `rent`: The rent sysvar.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `AccountNotRentExempt` if the account is underfunded.
+pub fn assert_account_rent_exempt(account: &AccountInfo, rent: &Rent) -> Result<(), ProgramError> {
+    if account.lamports() < rent.minimum_balance(account.data_len()) {
+        return Err(ProgramError::AccountNotRentExempt);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that zero is rejected and any other amount is accepted.
+    #[test]
+    fn require_nonzero_test() {
+        assert_eq!(
+            require_nonzero(0),
+            Err(ProgramError::Custom(ExampleError::ZeroAmount as u32))
+        );
+        assert!(require_nonzero(1).is_ok());
+        assert!(require_nonzero(u64::MAX).is_ok());
+    }
+
+    /// Tests that lamport moves fail instead of wrapping.
+    #[test]
+    fn checked_lamport_move_test() {
+        assert_eq!(checked_lamport_move(10, 5, 4), Ok((6, 9)));
+        assert_eq!(checked_lamport_move(10, 5, 10), Ok((0, 15)));
+
+        // The source cannot go below zero.
+        assert_eq!(
+            checked_lamport_move(10, 5, 11),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+
+        // The destination cannot go above `u64::MAX`.
+        assert_eq!(
+            checked_lamport_move(10, u64::MAX, 1),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+    }
+}
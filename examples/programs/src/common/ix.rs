@@ -0,0 +1,252 @@
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+/// The current instruction header version.
+pub const VERSION_1: u8 = 1;
+
+/// Splits instruction data into its header and payload.
+///
+/// The header is a 1-byte version followed by a 1-byte discriminator:
+///
+/// ```text
+/// [version: u8][discriminator: u8][payload..]
+/// ```
+///
+/// ### Parameters:
+/// - `data`: The raw instruction data.
+///
+/// ### Returns:
+/// - `Result<(u8, u8, &[u8]), ProgramError>`: The version, the discriminator and the
+///   remaining payload, or `InvalidInstructionData` if the header is truncated or the
+///   version is unknown.
+pub fn parse_header(data: &[u8]) -> Result<(u8, u8, &[u8]), ProgramError> {
+    let [version, discriminator, rest @ ..] = data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    // Reject versions this program does not understand.
+    if *version != VERSION_1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok((*version, *discriminator, rest))
+}
+
+/// Checks that instruction data starts with the expected discriminator.
+///
+/// Handlers reached through a dispatcher can re-assert their own discriminator, so a routing
+/// mistake fails cleanly instead of decoding another instruction's payload.
+///
+/// ### Parameters:
+/// - `data`: The instruction data, starting with the discriminator.
+/// - `expected`: The discriminator of the handler.
+///
+/// ### Returns:
+/// - `Result<&[u8], ProgramError>`: The data following the discriminator, or
+///   `InvalidInstructionData` if the data is empty or starts with another discriminator.
+pub fn expect_disc(data: &[u8], expected: u8) -> Result<&[u8], ProgramError> {
+    match data {
+        [discriminator, rest @ ..] if *discriminator == expected => Ok(rest),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Reads a length-prefixed seed at `offset`.
+///
+/// The seed is laid out as `[seed_len: u8][seed: seed_len]`. `create_with_seed` hashes the
+/// seed bytes as given, so a multibyte UTF-8 seed that renders like an ASCII one derives a
+/// different address; only ASCII seeds are accepted.
+///
+/// ### Parameters:
+/// - `data`: The instruction data.
+/// - `offset`: The offset of the seed length.
+///
+/// ### Returns:
+/// - `Result<&str, ProgramError>`: The seed, `InvalidInstructionData` if the data is too
+///   short, or `InvalidSeeds` if the seed is not ASCII.
+pub fn read_seed(data: &[u8], offset: usize) -> Result<&str, ProgramError> {
+    let seed_len = *data.get(offset).ok_or(ProgramError::InvalidInstructionData)? as usize;
+    let seed = data
+        .get(offset + 1..offset + 1 + seed_len)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    // Reject non-ASCII seeds, which also rules out invalid UTF-8.
+    if !seed.is_ascii() {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    core::str::from_utf8(seed).map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+/// Reads an 8-byte amount at `offset`.
+///
+/// Amounts are little-endian, matching the rest of the Solana ecosystem. With the
+/// `be_amounts` feature they are decoded as big-endian instead, for payloads produced by
+/// bridges that encode amounts that way.
+///
+/// ### Parameters:
+/// - `data`: The instruction data.
+/// - `offset`: The offset of the amount.
+///
+/// ### Returns:
+/// - `Result<u64, ProgramError>`: The amount, or `InvalidInstructionData` if the data is
+///   too short.
+pub fn read_amount(data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+    let bytes: [u8; 8] = offset
+        .checked_add(8)
+        .and_then(|end| data.get(offset..end))
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    #[cfg(feature = "be_amounts")]
+    let amount = u64::from_be_bytes(bytes);
+    #[cfg(not(feature = "be_amounts"))]
+    let amount = u64::from_le_bytes(bytes);
+
+    Ok(amount)
+}
+
+/// Encodes an amount the way [`read_amount`] decodes it.
+pub fn encode_amount(amount: u64) -> [u8; 8] {
+    #[cfg(feature = "be_amounts")]
+    let bytes = amount.to_be_bytes();
+    #[cfg(not(feature = "be_amounts"))]
+    let bytes = amount.to_le_bytes();
+
+    bytes
+}
+
+/// Copies a 32-byte public key out of the data at `offset`.
+///
+/// Unlike casting `data.as_ptr()` to `*const Pubkey`, this neither reads past the end of
+/// the data nor relies on its alignment.
+///
+/// ### Parameters:
+/// - `data`: The instruction data.
+/// - `offset`: The offset of the public key.
+///
+/// ### Returns:
+/// - `Result<Pubkey, ProgramError>`: The public key, or `InvalidInstructionData` if the data
+///   is too short.
+pub fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey, ProgramError> {
+    offset
+        .checked_add(32)
+        .and_then(|end| data.get(offset..end))
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that an amount round-trips through the active encoding.
+    #[test]
+    fn read_amount_round_trip_test() {
+        let mut data = vec![0xaa];
+        data.extend_from_slice(&encode_amount(0x0102_0304_0506_0708));
+
+        assert_eq!(read_amount(&data, 1), Ok(0x0102_0304_0506_0708));
+        assert_eq!(read_amount(&data, 2), Err(ProgramError::InvalidInstructionData));
+    }
+
+    /// Tests that amounts are little-endian by default.
+    #[cfg(not(feature = "be_amounts"))]
+    #[test]
+    fn read_amount_little_endian_test() {
+        assert_eq!(read_amount(&[1, 0, 0, 0, 0, 0, 0, 0], 0), Ok(1));
+        assert_eq!(encode_amount(1), [1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    /// Tests that amounts are big-endian with `be_amounts`.
+    #[cfg(feature = "be_amounts")]
+    #[test]
+    fn read_amount_big_endian_test() {
+        assert_eq!(read_amount(&[0, 0, 0, 0, 0, 0, 0, 1], 0), Ok(1));
+        assert_eq!(encode_amount(1), [0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    /// Tests that a known version is split into its header and payload.
+    #[test]
+    fn parse_header_known_version_test() {
+        let data = [VERSION_1, 3, 0xaa, 0xbb];
+
+        assert_eq!(parse_header(&data), Ok((VERSION_1, 3, &[0xaa, 0xbb][..])));
+
+        // A header without payload is valid.
+        assert_eq!(parse_header(&[VERSION_1, 0]), Ok((VERSION_1, 0, &[][..])));
+    }
+
+    /// Tests that unknown versions and truncated headers are rejected.
+    #[test]
+    fn parse_header_unknown_version_test() {
+        assert_eq!(parse_header(&[0, 0]), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(parse_header(&[2, 0, 1]), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(parse_header(&[VERSION_1]), Err(ProgramError::InvalidInstructionData));
+    }
+
+    /// Tests that empty data is rejected before any byte is read.
+    #[test]
+    fn parse_header_empty_data_test() {
+        assert_eq!(parse_header(&[]), Err(ProgramError::InvalidInstructionData));
+    }
+
+    /// Tests that a matching discriminator is stripped from the payload.
+    #[test]
+    fn expect_disc_matching_test() {
+        assert_eq!(expect_disc(&[3, 0xaa, 0xbb], 3), Ok(&[0xaa, 0xbb][..]));
+        assert_eq!(expect_disc(&[3], 3), Ok(&[][..]));
+    }
+
+    /// Tests that another discriminator, or none at all, is rejected.
+    #[test]
+    fn expect_disc_mismatching_test() {
+        assert_eq!(expect_disc(&[4, 0xaa], 3), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(expect_disc(&[], 3), Err(ProgramError::InvalidInstructionData));
+    }
+
+    /// Tests that an ASCII seed is read from its length prefix.
+    #[test]
+    fn read_seed_ascii_test() {
+        let mut data = vec![0xaa, 5];
+        data.extend_from_slice(b"vault");
+        data.push(0xbb);
+
+        assert_eq!(read_seed(&data, 1), Ok("vault"));
+        assert_eq!(read_seed(&[0], 0), Ok(""));
+    }
+
+    /// Tests that a non-ASCII seed is rejected with `InvalidSeeds`.
+    #[test]
+    fn read_seed_non_ascii_test() {
+        let seed = "vaült".as_bytes();
+        let mut data = vec![seed.len() as u8];
+        data.extend_from_slice(seed);
+
+        assert_eq!(read_seed(&data, 0), Err(ProgramError::InvalidSeeds));
+    }
+
+    /// Tests that a seed running past the end of the data is rejected.
+    #[test]
+    fn read_seed_truncated_test() {
+        assert_eq!(read_seed(&[5, b'v', b'a'], 0), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(read_seed(&[], 0), Err(ProgramError::InvalidInstructionData));
+    }
+
+    /// Tests reading a public key that fills the data exactly, and at an offset.
+    #[test]
+    fn read_pubkey_exact_size_test() {
+        assert_eq!(read_pubkey(&[7; 32], 0), Ok([7; 32]));
+
+        let mut data = vec![0xaa];
+        data.extend_from_slice(&[9; 32]);
+        assert_eq!(read_pubkey(&data, 1), Ok([9; 32]));
+    }
+
+    /// Tests that a buffer too short for a public key is rejected.
+    #[test]
+    fn read_pubkey_short_buffer_test() {
+        assert_eq!(read_pubkey(&[7; 31], 0), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(read_pubkey(&[7; 32], 1), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(read_pubkey(&[], usize::MAX), Err(ProgramError::InvalidInstructionData));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod accounts;
+#[cfg(not(target_os = "solana"))]
+pub mod encode;
+pub mod entry;
+pub mod error;
+pub mod guards;
+pub mod ix;
+pub mod signer;
+pub mod token_state;
@@ -0,0 +1,40 @@
+/// Builds a ready-to-use `[Signer; 1]` for a program-derived address.
+///
+/// Each argument is converted into a `Seed`, so byte strings, byte arrays and the
+/// `[u8; 1]` bump can be mixed freely:
+///
+/// ```ignore
+/// instruction.invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
+/// ```
+///
+/// The seeds are temporaries that live until the end of the enclosing statement, so the
+/// macro must be used inline in the `invoke_signed` call rather than bound with `let`.
+#[macro_export]
+macro_rules! pda_signer {
+    ($($seed:expr),+ $(,)?) => {
+        [pinocchio::instruction::Signer::from(&[
+            $(pinocchio::instruction::Seed::from($seed)),+
+        ])]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::instruction::Signer;
+
+    /// Returns the number of signers, forcing the macro output to type-check.
+    fn signer_count(signers: &[Signer]) -> usize {
+        signers.len()
+    }
+
+    /// Tests that the macro expands for one, two and three seeds.
+    #[test]
+    fn pda_signer_expands_test() {
+        let bump = [255u8];
+        let key = [7u8; 32];
+
+        assert_eq!(signer_count(&pda_signer!(&bump)), 1);
+        assert_eq!(signer_count(&pda_signer!(b"mint_authority", &bump)), 1);
+        assert_eq!(signer_count(&pda_signer!(b"mint", &key, &bump,)), 1);
+    }
+}
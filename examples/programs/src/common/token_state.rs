@@ -0,0 +1,383 @@
+use pinocchio::{
+    account_info::{AccountInfo, Ref},
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+};
+
+use super::error::ExampleError;
+
+/// The size of an SPL token account.
+pub const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// The size of an SPL token mint.
+pub const MINT_LEN: usize = 82;
+
+/// The Associated Token Account program.
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    five8_const::decode_32_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWBfA5knDvbd8Wp5v");
+
+/// Borrows the data of an account after checking it is owned by the token program.
+///
+/// ### Parameters:
+/// - `account`: The account to borrow.
+///
+/// ### Returns:
+/// - `Result<Ref<[u8]>, ProgramError>`: The borrowed account data.
+pub fn borrow_token_data(account: &AccountInfo) -> Result<Ref<[u8]>, ProgramError> {
+    // Ensure the account is owned by the token program.
+    if !account.is_owned_by(&pinocchio_token::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    account.try_borrow_data()
+}
+
+/// Reads an SPL `COption<Pubkey>` stored at `offset`.
+///
+/// The option is laid out as a 4-byte little-endian tag (`0` for `None`, `1` for `Some`)
+/// followed by the 32-byte key, which is present even when the tag is `None`.
+///
+/// ### Parameters:
+/// - `data`: The raw account data.
+/// - `offset`: The offset of the tag.
+///
+/// ### Returns:
+/// - `Result<Option<Pubkey>, ProgramError>`: The key, or `InvalidAccountData` if the data is
+///   too short or the tag is neither `0` nor `1`.
+pub fn read_coption_pubkey_at(data: &[u8], offset: usize) -> Result<Option<Pubkey>, ProgramError> {
+    let bytes = offset
+        .checked_add(36)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let (tag, key) = bytes.split_at(4);
+
+    match u32::from_le_bytes([tag[0], tag[1], tag[2], tag[3]]) {
+        0 => Ok(None),
+        1 => Ok(Some(key.try_into().unwrap())),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Ensures a key is the canonical associated token account of `owner` for `mint`.
+///
+/// The address is derived from `[owner, token program, mint]` under the Associated Token
+/// Account program, so any other token account of the same owner and mint is rejected.
+/// Deriving the address searches for the bump on-chain; prefer an explicit bump where the
+/// compute budget matters.
+///
+/// ### Parameters:
+/// - `passed`: The key of the account passed to the instruction.
+/// - `owner`: The wallet owning the associated token account.
+/// - `mint`: The mint of the associated token account.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidArgument` if `passed` is not the canonical address.
+pub fn require_ata(passed: &Pubkey, owner: &Pubkey, mint: &Pubkey) -> Result<(), ProgramError> {
+    let (ata, _) = find_program_address(
+        &[owner, &pinocchio_token::ID, mint],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+
+    if passed != &ata {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+/// A zero-copy view over the data of an SPL token account.
+///
+/// ### Layout:
+/// - `0..32`: The mint.
+/// - `32..64`: The owner.
+/// - `64..72`: The amount.
+/// - `72..108`: The delegate (`COption<Pubkey>`).
+/// - `108`: The account state.
+/// - `109..121`: The native reserve (`COption<u64>`).
+/// - `121..129`: The delegated amount.
+/// - `129..165`: The close authority (`COption<Pubkey>`).
+pub struct TokenAccount<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> TokenAccount<'a> {
+    /// Creates a view over raw token account data, validating its length.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if data.len() != TOKEN_ACCOUNT_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { data })
+    }
+
+    /// The mint this account holds tokens of.
+    pub fn mint(&self) -> &Pubkey {
+        unsafe { &*(self.data.as_ptr() as *const Pubkey) }
+    }
+
+    /// The owner of this account.
+    pub fn owner(&self) -> &Pubkey {
+        unsafe { &*(self.data.as_ptr().add(32) as *const Pubkey) }
+    }
+
+    /// The amount of tokens this account holds.
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(unsafe { *(self.data.as_ptr().add(64) as *const [u8; 8]) })
+    }
+
+    /// The delegate of this account, if any.
+    pub fn delegate(&self) -> Result<Option<Pubkey>, ProgramError> {
+        read_coption_pubkey_at(self.data, 72)
+    }
+
+    /// Whether this account is frozen (`AccountState::Frozen`).
+    pub fn is_frozen(&self) -> bool {
+        self.data[108] == 2
+    }
+}
+
+/// Ensures two token accounts hold the same mint.
+///
+/// ### Parameters:
+/// - `a`: The first token account.
+/// - `b`: The second token account.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `ExampleError::MintMismatch` if the mints differ.
+pub fn require_same_mint(a: &TokenAccount, b: &TokenAccount) -> Result<(), ProgramError> {
+    if a.mint() != b.mint() {
+        return Err(ExampleError::MintMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// A zero-copy view over the data of an SPL token mint.
+///
+/// ### Layout:
+/// - `0..36`: The mint authority (`COption<Pubkey>`).
+/// - `36..44`: The supply.
+/// - `44`: The decimals.
+/// - `45`: Whether the mint is initialized.
+/// - `46..82`: The freeze authority (`COption<Pubkey>`).
+pub struct Mint<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Mint<'a> {
+    /// Creates a view over raw mint data, validating its length.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if data.len() != MINT_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { data })
+    }
+
+    /// The authority allowed to mint new tokens, if any.
+    pub fn mint_authority(&self) -> Result<Option<Pubkey>, ProgramError> {
+        read_coption_pubkey_at(self.data, 0)
+    }
+
+    /// The total supply of tokens.
+    pub fn supply(&self) -> u64 {
+        u64::from_le_bytes(unsafe { *(self.data.as_ptr().add(36) as *const [u8; 8]) })
+    }
+
+    /// The number of base-10 digits to the right of the decimal point.
+    pub fn decimals(&self) -> u8 {
+        self.data[44]
+    }
+
+    /// Whether the mint has been initialized.
+    pub fn is_initialized(&self) -> bool {
+        self.data[45] == 1
+    }
+
+    /// The authority allowed to freeze token accounts of the mint, if any.
+    pub fn freeze_authority(&self) -> Result<Option<Pubkey>, ProgramError> {
+        read_coption_pubkey_at(self.data, 46)
+    }
+}
+
+/// Ensures raw mint data is an initialized mint.
+///
+/// The token program accepts an uninitialized mint in some instructions only to fail later,
+/// so the mint is checked before it is relied on.
+///
+/// ### Parameters:
+/// - `data`: The raw mint data.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidAccountData` if the data is not the size of a mint,
+///   or `UninitializedAccount` if the mint is not initialized.
+pub fn require_initialized_mint(data: &[u8]) -> Result<(), ProgramError> {
+    if !Mint::from_bytes(data)?.is_initialized() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{program_option::COption, program_pack::Pack};
+    use spl_token::state::{Account, AccountState};
+
+    /// Packs an SPL token account into raw bytes.
+    fn packed_account(mint: [u8; 32], owner: [u8; 32], amount: u64) -> [u8; TOKEN_ACCOUNT_LEN] {
+        let mut data = [0u8; TOKEN_ACCOUNT_LEN];
+        Account {
+            mint: mint.into(),
+            owner: owner.into(),
+            amount,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    /// Tests that the view reads the fields written by the SPL token packer.
+    #[test]
+    fn token_account_fields_test() {
+        let data = packed_account([1; 32], [2; 32], 1_234);
+        let account = TokenAccount::from_bytes(&data).unwrap();
+
+        assert_eq!(account.mint(), &[1; 32]);
+        assert_eq!(account.owner(), &[2; 32]);
+        assert_eq!(account.amount(), 1_234);
+        assert!(!account.is_frozen());
+    }
+
+    /// Tests that a frozen account is reported as such.
+    #[test]
+    fn token_account_frozen_test() {
+        let mut data = packed_account([1; 32], [2; 32], 10);
+        let mut account = Account::unpack_from_slice(&data).unwrap();
+        account.state = AccountState::Frozen;
+        account.pack_into_slice(&mut data);
+
+        assert!(TokenAccount::from_bytes(&data).unwrap().is_frozen());
+    }
+
+    /// Tests reading a `Some` tag, both directly and through `delegate()`.
+    #[test]
+    fn read_coption_pubkey_some_test() {
+        let mut data = packed_account([1; 32], [2; 32], 10);
+        let mut account = Account::unpack_from_slice(&data).unwrap();
+        account.delegate = COption::Some([7; 32].into());
+        account.delegated_amount = 5;
+        account.pack_into_slice(&mut data);
+
+        assert_eq!(read_coption_pubkey_at(&data, 72), Ok(Some([7; 32])));
+        assert_eq!(TokenAccount::from_bytes(&data).unwrap().delegate(), Ok(Some([7; 32])));
+    }
+
+    /// Tests reading a `None` tag, and rejecting invalid tags and truncated data.
+    #[test]
+    fn read_coption_pubkey_none_test() {
+        let mut data = packed_account([1; 32], [2; 32], 10);
+
+        assert_eq!(read_coption_pubkey_at(&data, 72), Ok(None));
+        assert_eq!(TokenAccount::from_bytes(&data).unwrap().delegate(), Ok(None));
+
+        // The close authority sits at the very end of the account.
+        assert_eq!(read_coption_pubkey_at(&data, 129), Ok(None));
+        assert!(read_coption_pubkey_at(&data, 130).is_err());
+
+        // Only `0` and `1` are valid tags.
+        data[72] = 2;
+        assert_eq!(
+            read_coption_pubkey_at(&data, 72),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    /// Tests that data of the wrong length is rejected.
+    #[test]
+    fn token_account_invalid_length_test() {
+        let data = [0u8; TOKEN_ACCOUNT_LEN - 1];
+        assert!(TokenAccount::from_bytes(&data).is_err());
+    }
+
+    /// Tests that accounts of the same mint are accepted.
+    #[test]
+    fn require_same_mint_matching_test() {
+        let a = packed_account([1; 32], [2; 32], 10);
+        let b = packed_account([1; 32], [3; 32], 20);
+
+        assert!(require_same_mint(
+            &TokenAccount::from_bytes(&a).unwrap(),
+            &TokenAccount::from_bytes(&b).unwrap(),
+        )
+        .is_ok());
+    }
+
+    /// Tests that accounts of different mints are rejected with `MintMismatch`.
+    #[test]
+    fn require_same_mint_mismatched_test() {
+        let a = packed_account([1; 32], [2; 32], 10);
+        let b = packed_account([4; 32], [2; 32], 10);
+
+        assert_eq!(
+            require_same_mint(
+                &TokenAccount::from_bytes(&a).unwrap(),
+                &TokenAccount::from_bytes(&b).unwrap(),
+            ),
+            Err(ProgramError::Custom(ExampleError::MintMismatch as u32))
+        );
+    }
+
+    /// Tests that initialized mints are accepted and uninitialized or misshaped data rejected.
+    #[test]
+    fn require_initialized_mint_test() {
+        let mut data = [0u8; MINT_LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply: 0,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        assert_eq!(require_initialized_mint(&data), Ok(()));
+
+        assert_eq!(
+            require_initialized_mint(&[0; MINT_LEN]),
+            Err(ProgramError::UninitializedAccount)
+        );
+        assert_eq!(
+            require_initialized_mint(&[0; TOKEN_ACCOUNT_LEN]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    /// Tests that the mint view reads the fields written by the SPL token packer.
+    #[test]
+    fn mint_fields_test() {
+        let mut data = [0u8; MINT_LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::Some([1; 32].into()),
+            supply: 123_456_789,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: COption::Some([2; 32].into()),
+        }
+        .pack_into_slice(&mut data);
+
+        let mint = Mint::from_bytes(&data).unwrap();
+        assert_eq!(mint.mint_authority(), Ok(Some([1; 32])));
+        assert_eq!(mint.supply(), 123_456_789);
+        assert_eq!(mint.decimals(), 9);
+        assert!(mint.is_initialized());
+        assert_eq!(mint.freeze_authority(), Ok(Some([2; 32])));
+
+        assert!(!Mint::from_bytes(&[0; MINT_LEN]).unwrap().is_initialized());
+        assert!(Mint::from_bytes(&data[..MINT_LEN - 1]).is_err());
+    }
+}
@@ -1,9 +1,22 @@
+pub mod common;
+
+pub mod state;
+
+#[cfg(not(target_os = "solana"))]
+pub mod client;
+
+#[cfg(test)]
+pub mod test_utils;
+
 mod system;
 use system::*;
 
 mod token;
 use token::*;
 
+mod mixed;
+use mixed::*;
+
 // /// System Program Instructions
 // #[cfg(feature = "advance_nonce_account")]
 // mod advance_nonce_account;
@@ -144,4 +157,21 @@ use token::*;
 // #[cfg(feature = "transfer_checked")]
 // mod transfer_checked;
 // #[cfg(feature = "transfer_checked")]
-// use transfer_checked::*;
\ No newline at end of file
+// use transfer_checked::*;
+
+#[cfg(test)]
+mod tests {
+    /// Tests that the instruction processors of the examples compile and are reachable.
+    ///
+    /// Several examples have no Mollusk test of their own; naming their processors here
+    /// makes a missing import or a stale signature fail `cargo test` instead of going
+    /// unnoticed.
+    #[test]
+    fn examples_compile_smoke_test() {
+        let _ = crate::token::approve_checked::process_approve_checked;
+        let _ = crate::token::burn::process_burn;
+        let _ = crate::token::mint_to_checked::process_mint_to_checked;
+        let _ = crate::system::withdraw_nonce_account::process_withdraw_nonce_account;
+        let _ = crate::system::transfer_with_seed::process_transfer_with_seed;
+    }
+}
@@ -0,0 +1,3 @@
+pub mod pay_combined;
+
+// pub use pay_combined::*;
@@ -0,0 +1,303 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::Transfer as SystemTransfer;
+use pinocchio_token::instructions::Transfer as TokenTransfer;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for both amounts.
+    if data.len() < 16 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the lamports to transfer from the data.
+    let lamports = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Extract the amount of tokens to transfer from the data.
+    let amount = unsafe { *(data.as_ptr().add(8) as *const u64) };
+
+    // Process the combined payment instruction.
+    process_pay_combined(accounts, lamports, amount)
+}
+
+/// Processes the combined payment instruction.
+///
+/// This function pays in SOL and tokens at once: a system `Transfer` moves lamports from
+/// the payer to the recipient wallet, and a token `Transfer` moves tokens between the
+/// token accounts. Both CPIs run in the same instruction, so either both apply or neither.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the payment.
+/// - `lamports`: The amount of lamports to transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The payer, funding the lamports and owning the source token account.
+/// 1. `[WRITE]` The wallet receiving the lamports.
+/// 2. `[WRITE]` The source token account.
+/// 3. `[WRITE]` The destination token account.
+/// 4. `[]` The system program.
+/// 5. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_pay_combined(
+    accounts: &[AccountInfo],
+    lamports: u64, // The amount of lamports to transfer.
+    amount: u64,   // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [payer_account, recipient_account, source_token_account, destination_token_account, _system_program, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the payer account is a signer.
+    if !payer_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure all the accounts being debited or credited are writable.
+    if !payer_account.is_writable()
+        || !recipient_account.is_writable()
+        || !source_token_account.is_writable()
+        || !destination_token_account.is_writable()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the lamport accounts and the token accounts are distinct sets: a token
+    // account passed as the lamport recipient (or vice versa) is a client error.
+    let lamport_keys = [payer_account.key(), recipient_account.key()];
+    let token_keys = [source_token_account.key(), destination_token_account.key()];
+    if lamport_keys[0] == lamport_keys[1]
+        || token_keys[0] == token_keys[1]
+        || lamport_keys.iter().any(|key| token_keys.contains(key))
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Transfer the lamports to the recipient.
+    SystemTransfer {
+        from: payer_account,
+        to: recipient_account,
+        lamports,
+    }
+    .invoke()?;
+
+    // Transfer the tokens to the destination.
+    TokenTransfer {
+        from: source_token_account,
+        to: destination_token_account,
+        authority: payer_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that the lamports and the tokens are both transferred.
+    #[test]
+    fn pay_combined_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the programs and Mollusk environment.
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Define the accounts.
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let payer = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let source_ta = Pubkey::new_unique();
+        let destination_ta = Pubkey::new_unique();
+
+        // Pay 5_000 lamports and 300 tokens.
+        let mut data = 5_000_u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&300_u64.to_le_bytes());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                payer => (payer, true, true),
+                recipient => (recipient, false, true),
+                source => (source_ta, false, true),
+                destination => (destination_ta, false, true),
+                system_program => (system_program, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (payer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (recipient, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (source_ta, token_account(&mollusk, mint, payer, 1_000)),
+                (destination_ta, token_account(&mollusk, mint, recipient, 0)),
+                (system_program, system_program_account),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // Both the lamport and the token balances changed.
+        let lamports = |key: &Pubkey| result.get_account(key).unwrap().lamports();
+        let tokens = |key: &Pubkey| {
+            spl_token::state::Account::unpack(result.get_account(key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+
+        assert_eq!(lamports(&payer), 1_000_000_000 - 5_000);
+        assert_eq!(lamports(&recipient), 5_000);
+        assert_eq!(tokens(&source_ta), 700);
+        assert_eq!(tokens(&destination_ta), 300);
+    }
+
+    /// Tests that a failing token transfer aborts the lamport transfer as well.
+    #[test]
+    fn pay_combined_atomic_failure_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the programs and Mollusk environment.
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Define the accounts.
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let payer = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let source_ta = Pubkey::new_unique();
+        let destination_ta = Pubkey::new_unique();
+
+        // Request more tokens than the source holds.
+        let mut data = 5_000_u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&2_000_u64.to_le_bytes());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                payer => (payer, true, true),
+                recipient => (recipient, false, true),
+                source => (source_ta, false, true),
+                destination => (destination_ta, false, true),
+                system_program => (system_program, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (payer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (recipient, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (source_ta, token_account(&mollusk, mint, payer, 1_000)),
+                (destination_ta, token_account(&mollusk, mint, recipient, 0)),
+                (system_program, system_program_account),
+                (token_program, token_program_account),
+            ],
+        );
+
+        // The instruction fails, so neither transfer is committed.
+        assert!(result.program_result.is_err());
+    }
+
+    /// Tests that a token account cannot double as the lamport recipient.
+    #[test]
+    fn pay_combined_overlapping_accounts_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the programs and Mollusk environment.
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // The destination token account is also passed as the lamport recipient.
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let payer = Pubkey::new_unique();
+        let source_ta = Pubkey::new_unique();
+        let destination_ta = Pubkey::new_unique();
+
+        let mut data = 5_000_u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&300_u64.to_le_bytes());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                payer => (payer, true, true),
+                recipient => (destination_ta, false, true),
+                source => (source_ta, false, true),
+                destination => (destination_ta, false, true),
+                system_program => (system_program, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (payer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (source_ta, token_account(&mollusk, mint, payer, 1_000)),
+                (destination_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (system_program, system_program_account),
+                (token_program, token_program_account),
+            ],
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
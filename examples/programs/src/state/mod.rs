@@ -0,0 +1 @@
+pub mod tagged_account;
@@ -0,0 +1,126 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// The size of the discriminator stored at the start of tagged account data.
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Writes `disc` at the start of `data`.
+///
+/// ### Parameters:
+/// - `data`: The raw account data.
+/// - `disc`: The discriminator of the account type.
+///
+/// ### Returns:
+/// - `ProgramResult`: `AccountDataTooSmall` if the data cannot hold the tag, or
+///   `AccountAlreadyInitialized` if a tag is already present.
+pub fn write_tag(data: &mut [u8], disc: [u8; DISCRIMINATOR_LEN]) -> ProgramResult {
+    let tag = data
+        .get_mut(..DISCRIMINATOR_LEN)
+        .ok_or(ProgramError::AccountDataTooSmall)?;
+
+    // A non-zero tag means the account already holds some account type.
+    if tag.iter().any(|byte| *byte != 0) {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    tag.copy_from_slice(&disc);
+
+    Ok(())
+}
+
+/// Ensures `data` starts with `expected_disc`.
+///
+/// ### Parameters:
+/// - `data`: The raw account data.
+/// - `expected_disc`: The discriminator of the expected account type.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidAccountData` if the data is too short or holds
+///   another tag.
+pub fn check_tag(data: &[u8], expected_disc: [u8; DISCRIMINATOR_LEN]) -> Result<(), ProgramError> {
+    if data.get(..DISCRIMINATOR_LEN) != Some(&expected_disc[..]) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Tags a freshly allocated account with the discriminator of its type.
+///
+/// Without a tag, two account types of the same size are indistinguishable, so an attacker
+/// could pass one where the other is expected. The owner check is left to the caller.
+///
+/// ### Parameters:
+/// - `account`: The account to tag, allocated and owned by this program.
+/// - `disc`: The discriminator of the account type.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the tagging.
+pub fn init_tagged(account: &AccountInfo, disc: [u8; DISCRIMINATOR_LEN]) -> ProgramResult {
+    write_tag(&mut account.try_borrow_mut_data()?, disc)
+}
+
+/// Ensures an account holds the expected account type before its data is trusted.
+///
+/// ### Parameters:
+/// - `account`: The account to validate.
+/// - `expected_disc`: The discriminator of the expected account type.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidAccountData` if the tag does not match.
+pub fn load_tagged(
+    account: &AccountInfo,
+    expected_disc: [u8; DISCRIMINATOR_LEN],
+) -> Result<(), ProgramError> {
+    check_tag(&account.try_borrow_data()?, expected_disc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VAULT: [u8; DISCRIMINATOR_LEN] = *b"vault\0\0\0";
+    const POOL: [u8; DISCRIMINATOR_LEN] = *b"pool\0\0\0\0";
+
+    /// Tests that tagging writes the discriminator and leaves the body untouched.
+    #[test]
+    fn init_tagged_test() {
+        let mut data = [0u8; 16];
+        data[DISCRIMINATOR_LEN] = 7;
+
+        assert!(write_tag(&mut data, VAULT).is_ok());
+        assert_eq!(data[..DISCRIMINATOR_LEN], VAULT);
+        assert_eq!(data[DISCRIMINATOR_LEN], 7);
+
+        // A tagged account cannot be re-tagged as another type.
+        assert_eq!(
+            write_tag(&mut data, POOL),
+            Err(ProgramError::AccountAlreadyInitialized)
+        );
+
+        // The data must be large enough for the tag.
+        assert_eq!(
+            write_tag(&mut [0u8; DISCRIMINATOR_LEN - 1], VAULT),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    /// Tests that data with the expected tag is accepted.
+    #[test]
+    fn load_tagged_test() {
+        let mut data = [0u8; 16];
+        write_tag(&mut data, VAULT).unwrap();
+
+        assert!(check_tag(&data, VAULT).is_ok());
+    }
+
+    /// Tests that another tag, a missing tag or truncated data is rejected.
+    #[test]
+    fn load_tagged_wrong_tag_test() {
+        let mut data = [0u8; 16];
+        write_tag(&mut data, VAULT).unwrap();
+
+        assert_eq!(check_tag(&data, POOL), Err(ProgramError::InvalidAccountData));
+        assert_eq!(check_tag(&[0u8; 16], VAULT), Err(ProgramError::InvalidAccountData));
+        assert_eq!(check_tag(&data[..4], VAULT), Err(ProgramError::InvalidAccountData));
+    }
+}
@@ -1,13 +1,16 @@
 use pinocchio::{
     account_info::AccountInfo,
     entrypoint,
+    msg,
     program_error::ProgramError,
-    pubkey::Pubkey,
+    pubkey::{log, Pubkey},
     ProgramResult,
 };
 
 use pinocchio_system::instructions::AdvanceNonceAccount;
 
+use crate::common::guards::require_system_program;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("77777777777777777777777777777777777777777777");
 
@@ -31,6 +34,28 @@ pub fn process_instruction(
     process_advance_nonce_account(accounts)
 }
 
+/// Reads the most recent blockhash from the recent blockhashes sysvar data.
+///
+/// The sysvar is serialized as a little-endian `u64` entry count followed by entries of
+/// `[blockhash: 32][lamports_per_signature: 8]`, most recent first.
+///
+/// ### Parameters:
+/// - `data`: The raw data of the recent blockhashes sysvar account.
+///
+/// ### Returns:
+/// - `Option<[u8; 32]>`: The first blockhash, or `None` if the sysvar is empty or truncated.
+pub fn first_recent_blockhash(data: &[u8]) -> Option<[u8; 32]> {
+    // Read the number of entries stored in the sysvar.
+    let len = u64::from_le_bytes(data.get(0..8)?.try_into().ok()?);
+
+    if len == 0 {
+        return None;
+    }
+
+    // The first entry starts right after the entry count.
+    data.get(8..40)?.try_into().ok()
+}
+
 /// Processes the `AdvanceNonceAccount` instruction.
 ///
 /// This function handles the logic for advancing a nonce account. It validates the accounts
@@ -43,22 +68,41 @@ pub fn process_instruction(
 /// 0. `[WRITE]` The Nonce account.
 /// 1. `[]` The recent blockhashes sysvar.
 /// 2. `[SIGNER]` The Nonce authority.
+/// 3. `[]` The system program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
 pub fn process_advance_nonce_account<'a>(accounts: &'a [AccountInfo]) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [nonce_account, recent_blockhashes_sysvar, nonce_authority, _system_program] = accounts
+    let [nonce_account, recent_blockhashes_sysvar, nonce_authority, system_program] = accounts
     else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    // Ensure the nonce account is writable.
+    if !nonce_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     // Ensure the nonce authority is a signer.
     if !nonce_authority.is_signer() {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Ensure the CPI is routed to the system program.
+    require_system_program(system_program)?;
+
+    // Log the most recent blockhash to help diagnose stale or reused nonces.
+    {
+        let sysvar_data = recent_blockhashes_sysvar.try_borrow_data()?;
+
+        if let Some(blockhash) = first_recent_blockhash(&sysvar_data) {
+            msg!("Recent blockhash:");
+            log(&blockhash);
+        }
+    }
+
     // Construct the `AdvanceNonceAccount` instruction.
     let advance_nonce_instruction = AdvanceNonceAccount {
         account: nonce_account,
@@ -74,13 +118,45 @@ pub fn process_advance_nonce_account<'a>(accounts: &'a [AccountInfo]) -> Program
 
 #[cfg(test)]
 mod tests {
-    use mollusk_svm::Mollusk;
+    use super::first_recent_blockhash;
+    use mollusk_svm::{result::Check, Mollusk};
     use solana_sdk::{
-        account::{AccountSharedData},
+        account::{AccountSharedData, WritableAccount},
+        hash::Hash,
         instruction::{AccountMeta, Instruction},
+        nonce::state::{Data, DurableNonce, State, Versions},
+        program_error::ProgramError,
         pubkey::Pubkey,
     };
 
+    /// Serializes a recent blockhashes sysvar holding the given blockhashes, most recent first.
+    fn recent_blockhashes_data(blockhashes: &[Hash]) -> Vec<u8> {
+        let mut data = (blockhashes.len() as u64).to_le_bytes().to_vec();
+        for blockhash in blockhashes {
+            data.extend_from_slice(blockhash.as_ref());
+            data.extend_from_slice(&5_000u64.to_le_bytes());
+        }
+        data
+    }
+
+    /// Tests that the first blockhash is read from the sysvar data.
+    #[test]
+    fn first_recent_blockhash_test() {
+        let newest = Hash::new_unique();
+        let oldest = Hash::new_unique();
+
+        // The first entry is returned.
+        let data = recent_blockhashes_data(&[newest, oldest]);
+        assert_eq!(first_recent_blockhash(&data), Some(newest.to_bytes()));
+
+        // An empty sysvar has no blockhash.
+        assert_eq!(first_recent_blockhash(&recent_blockhashes_data(&[])), None);
+
+        // Truncated data is ignored rather than read out of bounds.
+        assert_eq!(first_recent_blockhash(&data[..20]), None);
+        assert_eq!(first_recent_blockhash(&[]), None);
+    }
+
     /// Unit test for the `process_advance_nonce_account` function.
     ///
     /// This test sets up a mock environment using the `Mollusk` framework, creates
@@ -158,4 +234,187 @@ mod tests {
             "Error while processing instruction",
         );
     }
+
+    /// Tests that the nonce is still advanced when the sysvar holds blockhash entries.
+    #[test]
+    fn process_advance_nonce_account_populated_sysvar_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array(five8_const::decode_32_const(
+            "77777777777777777777777777777777777777777777",
+        ));
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "target/deploy/programs");
+
+        let nonce_account = Pubkey::new_unique();
+        let recent_blockhashes_sysvar = solana_sdk::sysvar::recent_blockhashes::ID;
+        let nonce_authority = Pubkey::new_unique();
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        // Store a durable nonce that differs from the current blockhash.
+        let nonce_state = Versions::new(State::Initialized(Data::new(
+            nonce_authority,
+            DurableNonce::from_blockhash(&Hash::new_unique()),
+            5_000,
+        )));
+        let nonce_account_data = AccountSharedData::new_data(
+            mollusk.sysvars.rent.minimum_balance(State::size()),
+            &nonce_state,
+            &solana_sdk::system_program::ID,
+        )
+        .unwrap();
+
+        // Populate the sysvar with two blockhash entries.
+        let mut sysvar_account = AccountSharedData::new(
+            1_000_000_000u64,
+            0,
+            &solana_sdk::sysvar::ID,
+        );
+        sysvar_account.set_data_from_slice(&recent_blockhashes_data(&[
+            Hash::new_unique(),
+            Hash::new_unique(),
+        ]));
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![
+                AccountMeta::new(nonce_account, false),
+                AccountMeta::new_readonly(recent_blockhashes_sysvar, false),
+                AccountMeta::new_readonly(nonce_authority, true),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        let result: mollusk_svm::result::InstructionResult = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (nonce_account, nonce_account_data),
+                (recent_blockhashes_sysvar, sysvar_account),
+                (
+                    nonce_authority,
+                    AccountSharedData::new(1_000_000_000u64, 0, &solana_sdk::system_program::ID),
+                ),
+                (system_program, system_program_account),
+            ],
+        );
+
+        // Reading the sysvar must not get in the way of the CPI.
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+    }
+
+    /// Builds an advance instruction for an initialized nonce account, with the nonce account
+    /// writable or not and the authority signing or not.
+    fn advance(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        writable: bool,
+        signer: bool,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let nonce_account = Pubkey::new_unique();
+        let recent_blockhashes_sysvar = solana_sdk::sysvar::recent_blockhashes::ID;
+        let nonce_authority = Pubkey::new_unique();
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let nonce_state = Versions::new(State::Initialized(Data::new(
+            nonce_authority,
+            DurableNonce::from_blockhash(&Hash::new_unique()),
+            5_000,
+        )));
+        let nonce_account_data = AccountSharedData::new_data(
+            mollusk.sysvars.rent.minimum_balance(State::size()),
+            &nonce_state,
+            &solana_sdk::system_program::ID,
+        )
+        .unwrap();
+
+        let mut sysvar_account = AccountSharedData::new(1_000_000_000u64, 0, &solana_sdk::sysvar::ID);
+        sysvar_account.set_data_from_slice(&recent_blockhashes_data(&[Hash::new_unique()]));
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![
+                AccountMeta {
+                    pubkey: nonce_account,
+                    is_signer: false,
+                    is_writable: writable,
+                },
+                AccountMeta::new_readonly(recent_blockhashes_sysvar, false),
+                AccountMeta::new_readonly(nonce_authority, signer),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        let accounts = vec![
+            (nonce_account, nonce_account_data),
+            (recent_blockhashes_sysvar, sysvar_account),
+            (
+                nonce_authority,
+                AccountSharedData::new(1_000_000_000u64, 0, &solana_sdk::system_program::ID),
+            ),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts)
+    }
+
+    /// Tests that an authority that did not sign is rejected with `MissingRequiredSignature`.
+    #[test]
+    fn process_advance_nonce_account_missing_signer_test() {
+        let program_id = Pubkey::new_from_array(five8_const::decode_32_const(
+            "77777777777777777777777777777777777777777777",
+        ));
+        let mollusk = Mollusk::new(&program_id, "target/deploy/programs");
+        let (instruction, accounts) = advance(&mollusk, program_id, true, false);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        );
+    }
+
+    /// Tests that a read-only nonce account is rejected with `InvalidAccountData`.
+    #[test]
+    fn process_advance_nonce_account_not_writable_test() {
+        let program_id = Pubkey::new_from_array(five8_const::decode_32_const(
+            "77777777777777777777777777777777777777777777",
+        ));
+        let mollusk = Mollusk::new(&program_id, "target/deploy/programs");
+        let (instruction, accounts) = advance(&mollusk, program_id, false, true);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    /// Tests that a program other than the system program is rejected with
+    /// `IncorrectProgramId`.
+    #[test]
+    fn process_advance_nonce_account_wrong_system_program_test() {
+        let program_id = Pubkey::new_from_array(five8_const::decode_32_const(
+            "77777777777777777777777777777777777777777777",
+        ));
+        let mollusk = Mollusk::new(&program_id, "target/deploy/programs");
+        let (mut instruction, mut accounts) = advance(&mollusk, program_id, true, true);
+
+        // Swap the system program for another program.
+        let fake_program = Pubkey::new_unique();
+        instruction.accounts[3].pubkey = fake_program;
+        accounts[3].0 = fake_program;
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::IncorrectProgramId)],
+        );
+    }
 }
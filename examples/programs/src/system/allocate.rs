@@ -54,6 +54,7 @@ pub fn process_instruction(
 ///
 /// ### Accounts:
 /// 0. `[WRITE, SIGNER]` The account to allocate space for.
+/// 1. `[]` The system program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -63,7 +64,7 @@ pub fn process_allocate<'a>(
     bump: [u8; 1],
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [allocate_account] = accounts else {
+    let [allocate_account, _system_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -87,4 +88,76 @@ pub fn process_allocate<'a>(
     allocate_instruction.invoke_signed(&signers)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds an allocation of `space` bytes, with the account signing or not.
+    fn allocate(
+        program_id: Pubkey,
+        space: u64,
+        signer: bool,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (account, bump) = Pubkey::find_program_address(&[b"seeds"], &program_id);
+
+        let mut data = space.to_le_bytes().to_vec();
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                account => (account, signer, true),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (account, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts, account)
+    }
+
+    /// Tests that the account ends up with the requested data length.
+    #[test]
+    fn allocate_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, account) = allocate(program_id, 128, true);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        assert_eq!(result.get_account(&account).unwrap().data().len(), 128);
+    }
+
+    /// Tests that an account that is not a signer is rejected.
+    #[test]
+    fn allocate_missing_signer_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = allocate(program_id, 128, false);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        );
+    }
+}
@@ -9,6 +9,8 @@ use pinocchio::{
 
 use pinocchio_system::instructions::AllocateWithSeed;
 
+use crate::common::ix::read_seed;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -39,9 +41,7 @@ pub fn process_instruction(
         return Err(ProgramError::InvalidInstructionData);
     }
     
-    let seed = unsafe {
-        std::str::from_utf8_unchecked(&data[1..1 + seed_len])
-    };
+    let seed = read_seed(data, 0)?;
 
     // Extract `space` (u64) from the next 8 bytes after the seed
     let space_offset = 1 + seed_len;
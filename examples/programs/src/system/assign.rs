@@ -9,6 +9,8 @@ use pinocchio::{
 
 use pinocchio_system::instructions::Assign;
 
+use crate::common::ix::read_pubkey;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -35,7 +37,7 @@ pub fn process_instruction(
     }
 
     // Extract the owner public key from the data.
-    let owner = unsafe { *(data.as_ptr() as *const Pubkey) };
+    let owner = read_pubkey(data, 0)?;
 
     // Extract the bump seed from the data.
     let bump: [u8; 1] = unsafe { *(data.as_ptr().add(32) as *const [u8; 1]) };
@@ -9,6 +9,8 @@ use pinocchio::{
 
 use pinocchio_system::instructions::AssignWithSeed;
 
+use crate::common::ix::read_seed;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -43,9 +45,7 @@ pub fn process_instruction(
     }
 
     // Extract the seed from the instruction data.
-    let seed = unsafe {
-        std::str::from_utf8_unchecked(&data[1..1 + seed_len])
-    };
+    let seed = read_seed(data, 0)?;
 
     // Extract the owner public key from the instruction data.
     let owner_offset = 1 + seed_len;
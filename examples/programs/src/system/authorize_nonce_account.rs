@@ -9,6 +9,8 @@ use pinocchio::{
 
 use pinocchio_system::instructions::AuthorizeNonceAccount;
 
+use crate::common::ix::read_pubkey;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -35,7 +37,7 @@ pub fn process_instruction(
     }
 
     // Extract the new authority's public key from the instruction data.
-    let new_authority = unsafe { *(data.as_ptr() as *const Pubkey) };
+    let new_authority = read_pubkey(data, 0)?;
 
     // Extract the bump seed from the instruction data.
     let bump: [u8; 1] = unsafe { *(data.as_ptr().add(32) as *const [u8; 1]) };
@@ -0,0 +1,311 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::common::guards::{load_state, load_state_mut};
+
+/// The size of the counter account data: a single little-endian `u64`.
+pub const COUNTER_LEN: usize = 8;
+
+/// Discriminator for the increment instruction.
+pub const INCREMENT: u8 = 0;
+
+/// Discriminator for the read-only query instruction.
+pub const QUERY: u8 = 1;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Dispatch on the discriminator in the first byte of the data.
+    match data.first() {
+        Some(&INCREMENT) => process_increment(program_id, accounts),
+        Some(&QUERY) => process_query(program_id, accounts),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Reads the counter value from a validated counter account.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the counter account.
+/// - `counter_account`: The counter account to read.
+///
+/// ### Returns:
+/// - `Result<u64, ProgramError>`: The current counter value.
+fn read_counter(program_id: &Pubkey, counter_account: &AccountInfo) -> Result<u64, ProgramError> {
+    // Load the program-owned account, which must hold exactly one counter.
+    let data = load_state::<COUNTER_LEN>(counter_account, program_id)?;
+
+    Ok(u64::from_le_bytes(*data))
+}
+
+/// Processes the increment instruction.
+///
+/// This function increments the `u64` counter stored in a program-owned account. It
+/// validates the account, loads its data mutably, and writes the new value in place.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the counter account.
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The counter account, owned by this program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_increment(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [counter_account] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the counter account is writable.
+    if !counter_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Load the program-owned account, which must hold exactly one counter.
+    let data = load_state_mut::<COUNTER_LEN>(counter_account, program_id)?;
+
+    // Read the current value and increment it.
+    let count = u64::from_le_bytes(*data)
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Write the new value back to the account.
+    *data = count.to_le_bytes();
+
+    Ok(())
+}
+
+/// Processes the query instruction.
+///
+/// This function reads the counter without modifying it and sets the value as the
+/// transaction return data, so clients can read it without deserializing the account.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the counter account.
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[]` The counter account, owned by this program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_query(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [counter_account] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Read the counter value.
+    let count = read_counter(program_id, counter_account)?;
+
+    // Return the value as little-endian bytes.
+    set_return_data(&count.to_le_bytes());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{COUNTER_LEN, INCREMENT, QUERY};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that invoking the program twice leaves the counter at 2.
+    #[test]
+    fn counter_increment_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // Create a zeroed counter account owned by the program.
+        let counter = Pubkey::new_unique();
+        let mut counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(COUNTER_LEN),
+            COUNTER_LEN,
+            &program_id,
+        );
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[INCREMENT],
+            vec![AccountMeta::new(counter, false)],
+        );
+
+        // Invoke the program twice, feeding the resulting account into the next call.
+        for _ in 0..2 {
+            let result = mollusk.process_instruction(
+                &instruction,
+                &vec![(counter, counter_account.clone())],
+            );
+            assert!(
+                !result.program_result.is_err(),
+                "Error while processing instruction",
+            );
+            counter_account = result.get_account(&counter).unwrap().clone();
+        }
+
+        // The counter reads 2.
+        assert_eq!(counter_account.data(), &2u64.to_le_bytes());
+    }
+
+    /// Tests that the query instruction returns the counter as return data.
+    #[test]
+    fn counter_query_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // Create a counter account that already holds 42.
+        let counter = Pubkey::new_unique();
+        let mut counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(COUNTER_LEN),
+            COUNTER_LEN,
+            &program_id,
+        );
+        counter_account.set_data_from_slice(&42u64.to_le_bytes());
+
+        // The counter is passed read-only, the query never writes.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[QUERY],
+            vec![AccountMeta::new_readonly(counter, false)],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![(counter, counter_account.clone())],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The return data holds the little-endian counter and the account is unchanged.
+        assert_eq!(result.return_data, 42u64.to_le_bytes().to_vec());
+        assert_eq!(result.get_account(&counter).unwrap().data(), counter_account.data());
+    }
+
+    /// Tests that querying a counter account of the wrong length is rejected.
+    #[test]
+    fn counter_query_wrong_length_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // The counter account holds one byte too many.
+        let counter = Pubkey::new_unique();
+        let counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(COUNTER_LEN + 1),
+            COUNTER_LEN + 1,
+            &program_id,
+        );
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[QUERY],
+            vec![AccountMeta::new_readonly(counter, false)],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![(counter, counter_account)],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    /// Tests that incrementing a counter account of the wrong length is rejected.
+    #[test]
+    fn counter_increment_wrong_length_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // The counter account holds one byte too few.
+        let counter = Pubkey::new_unique();
+        let counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(COUNTER_LEN - 1),
+            COUNTER_LEN - 1,
+            &program_id,
+        );
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[INCREMENT],
+            vec![AccountMeta::new(counter, false)],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![(counter, counter_account)],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    /// Tests that a counter account owned by another program is rejected.
+    #[test]
+    fn counter_foreign_owner_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // The counter account is owned by some other program.
+        let counter = Pubkey::new_unique();
+        let counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(COUNTER_LEN),
+            COUNTER_LEN,
+            &Pubkey::new_unique(),
+        );
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[INCREMENT],
+            vec![AccountMeta::new(counter, false)],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![(counter, counter_account)],
+            &[Check::err(ProgramError::InvalidAccountOwner)],
+        );
+    }
+}
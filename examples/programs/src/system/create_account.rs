@@ -4,11 +4,14 @@ use pinocchio::{
     program_error::ProgramError,
     instruction::{Signer, Seed},
     pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
     ProgramResult
 };
 
 use pinocchio_system::instructions::CreateAccount;
 
+use crate::common::guards::assert_account_rent_exempt;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -59,6 +62,7 @@ pub fn process_instruction(
 /// ### Accounts:
 /// 0. `[WRITE, SIGNER]` The funding account.
 /// 1. `[WRITE, SIGNER]` The new account to be created.
+/// 2. `[]` The system program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -70,7 +74,7 @@ pub fn process_create_account<'a>(
     bump: [u8; 1],
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [funding_account, new_account] = accounts else {
+    let [funding_account, new_account, _system_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -96,5 +100,124 @@ pub fn process_create_account<'a>(
     // Invoke the instruction with the provided signer.
     create_account_instruction.invoke_signed(&signer)?;
 
+    // Ensure the new account was funded to rent exemption.
+    assert_account_rent_exempt(new_account, &Rent::get()?)?;
+
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a `CreateAccount` with the given signer flags for the funding and new accounts.
+    fn create(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        funding_signs: bool,
+        new_signs: bool,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let funding = Pubkey::new_unique();
+        let new_account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let lamports = mollusk.sysvars.rent.minimum_balance(16);
+
+        // Encode `[lamports][space][owner][bump]`.
+        let mut data = lamports.to_le_bytes().to_vec();
+        data.extend_from_slice(&16_u64.to_le_bytes());
+        data.extend_from_slice(owner.as_ref());
+        data.push(0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                funding => (funding, funding_signs, true),
+                new_account => (new_account, new_signs, true),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (funding, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (new_account, AccountSharedData::default()),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts, new_account, owner)
+    }
+
+    /// Tests that the account is created when both accounts sign.
+    #[test]
+    fn create_account_both_signers_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, new_account, owner) = create(&mollusk, program_id, true, true);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let created = result.get_account(&new_account).unwrap();
+        assert_eq!(created.data().len(), 16);
+        assert_eq!(created.owner(), &owner);
+    }
+
+    /// Tests that the new account must sign.
+    #[test]
+    fn create_account_only_funding_signs_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = create(&mollusk, program_id, true, false);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        );
+    }
+
+    /// Tests that the funding account must sign.
+    #[test]
+    fn create_account_only_new_account_signs_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = create(&mollusk, program_id, false, true);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        );
+    }
+
+    /// Tests that an underfunded new account is rejected after creation.
+    #[test]
+    fn create_account_underfunded_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (mut instruction, accounts, ..) = create(&mollusk, program_id, true, true);
+
+        // Fund the account with a single lamport, far below rent exemption.
+        instruction.data[..8].copy_from_slice(&1_u64.to_le_bytes());
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::AccountNotRentExempt)],
+        );
+    }
+}
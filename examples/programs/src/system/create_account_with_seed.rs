@@ -4,11 +4,15 @@ use pinocchio::{
     program_error::ProgramError,
     instruction::{Signer, Seed},
     pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 
 use pinocchio_system::instructions::CreateAccountWithSeed;
 
+use crate::common::ix::read_seed;
+use crate::common::guards::assert_account_rent_exempt;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -43,7 +47,7 @@ pub fn process_instruction(
     }
 
     // Extract the seed string from the instruction data.
-    let seed = unsafe { std::str::from_utf8_unchecked(&data[1..1 + seed_len]) };
+    let seed = read_seed(data, 0)?;
 
     // Extract the lamports value from the instruction data.
     let lamports_offset = 1 + seed_len;
@@ -82,6 +86,7 @@ pub fn process_instruction(
 /// 0. `[WRITE, SIGNER]` The funding account.
 /// 1. `[WRITE, SIGNER]` The new account to be created.
 /// 2. `[OPTIONAL]` The base account used to derive the new account (if applicable).
+/// 3. `[]` The system program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -94,7 +99,7 @@ pub fn process_create_account_with_seed<'a>(
     bump: [u8; 1],      // The bump seed used for address derivation.
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [funding_account, new_account, base_account] = accounts else {
+    let [funding_account, new_account, base_account, _system_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -120,5 +125,118 @@ pub fn process_create_account_with_seed<'a>(
     // Invoke the instruction with the provided signer.
     create_account_with_seed_instruction.invoke_signed(&signer)?;
 
+    // Ensure the new account was funded to rent exemption.
+    assert_account_rent_exempt(new_account, &Rent::get()?)?;
+
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::build_create_account_with_seed_ix;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that an instruction built by the client is parsed and processed on-chain.
+    #[test]
+    fn create_account_with_seed_client_round_trip_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the system program and Mollusk environment.
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // Derive the new account from the base, the seed and the owner.
+        let funding = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let seed = "round_trip";
+        let new_account = Pubkey::create_with_seed(&base, seed, &owner).unwrap();
+        let (_, bump) = Pubkey::find_program_address(&[b"funding_account"], &program_id);
+
+        let space = 32_u64;
+        let lamports = mollusk.sysvars.rent.minimum_balance(space as usize);
+
+        let instruction = build_create_account_with_seed_ix(
+            &program_id,
+            &funding,
+            &new_account,
+            &base,
+            seed,
+            lamports,
+            space,
+            &owner,
+            bump,
+        )
+        .unwrap();
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (funding, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (new_account, AccountSharedData::default()),
+                (base, AccountSharedData::default()),
+                (system_program, system_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The account was created with the encoded parameters.
+        let created = result.get_account(&new_account).unwrap();
+        assert_eq!(created.lamports(), lamports);
+        assert_eq!(created.data().len(), space as usize);
+        assert_eq!(created.owner(), &owner);
+    }
+
+    /// Tests that a seed with invalid UTF-8 bytes is rejected with `InvalidSeeds`, as any
+    /// non-ASCII seed is.
+    #[test]
+    fn create_account_with_seed_invalid_utf8_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        let funding = Pubkey::new_unique();
+        let new_account = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+
+        // Build a valid instruction, then corrupt the seed bytes in place.
+        let mut instruction = build_create_account_with_seed_ix(
+            &program_id,
+            &funding,
+            &new_account,
+            &base,
+            "seed",
+            0,
+            0,
+            &Pubkey::new_unique(),
+            0,
+        )
+        .unwrap();
+        instruction.data[1..5].copy_from_slice(&[0xff, 0xfe, 0xfd, 0xfc]);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (funding, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (new_account, AccountSharedData::default()),
+                (base, AccountSharedData::default()),
+                (system_program, system_program_account),
+            ],
+            &[Check::err(ProgramError::InvalidSeeds)],
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::CreateAccountWithSeed;
+
+use crate::common::guards::{assert_account_rent_exempt, require_system_program};
+use crate::common::ix::{read_pubkey, read_seed};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Extract the seed from the instruction data.
+    let seed = read_seed(data, 0)?;
+
+    // Validate the total length of the instruction data.
+    let lamports_offset = 1 + seed.len();
+    if data.len() < lamports_offset + 8 + 8 + 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the lamports value from the instruction data.
+    let lamports = unsafe { *(data.as_ptr().add(lamports_offset) as *const u64) };
+
+    // Extract the space value from the instruction data.
+    let space_offset = lamports_offset + 8;
+    let space = unsafe { *(data.as_ptr().add(space_offset) as *const u64) };
+
+    // Extract the owner public key from the instruction data.
+    let owner = read_pubkey(data, space_offset + 8)?;
+
+    // Process the `CreateAccountWithSeed` instruction with the funding account as base.
+    process_create_account_with_seed_self(accounts, seed, lamports, space, &owner)
+}
+
+/// Processes the `CreateAccountWithSeed` instruction, deriving from the funding account.
+///
+/// Passing `None` as the base makes the system program use the funding account as the
+/// derivation base, so the new account must be `create_with_seed(funding, seed, owner)`.
+/// No separate base account is needed, and the funding account's signature covers both
+/// roles. The system program rejects a new account derived from any other base.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `seed`: The seed used to derive the account.
+/// - `lamports`: The number of lamports to transfer to the new account.
+/// - `space`: The number of bytes to allocate for the new account.
+/// - `owner`: The program that will own the new account.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The funding account, also the derivation base.
+/// 1. `[WRITE]` The new account, derived from the funding account.
+/// 2. `[]` The system program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_create_account_with_seed_self<'a>(
+    accounts: &'a [AccountInfo],
+    seed: &'a str,  // The ASCII string that will be used as the seed to derive the address.
+    lamports: u64,  // Number of lamports to transfer to the new account.
+    space: u64,     // Number of bytes to allocate for the new account.
+    owner: &Pubkey, // Pubkey of the program that will own the new account.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [funding_account, new_account, system_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the CPI goes to the system program.
+    require_system_program(system_program)?;
+
+    // Ensure the funding account, which is also the base, signed.
+    if !funding_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the funding account and the new account are writable.
+    if !funding_account.is_writable() || !new_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Create the account, deriving its address from the funding account.
+    CreateAccountWithSeed {
+        from: funding_account,
+        to: new_account,
+        base: None,
+        seed,
+        lamports,
+        space,
+        owner,
+    }
+    .invoke()?;
+
+    // Ensure the new account was funded to rent exemption.
+    assert_account_rent_exempt(new_account, &Rent::get()?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        pubkey::Pubkey,
+    };
+
+    /// Builds the creation of `new_account` with the funding account as base.
+    fn create(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        funding: Pubkey,
+        new_account: Pubkey,
+        seed: &str,
+        space: u64,
+        owner: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        // Encode `[seed_len][seed][lamports][space][owner]`.
+        let mut data = vec![seed.len() as u8];
+        data.extend_from_slice(seed.as_bytes());
+        data.extend_from_slice(&mollusk.sysvars.rent.minimum_balance(space as usize).to_le_bytes());
+        data.extend_from_slice(&space.to_le_bytes());
+        data.extend_from_slice(owner.as_ref());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                funding => (funding, true, true),
+                new_account => (new_account, false, true),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (funding, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (new_account, AccountSharedData::default()),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts)
+    }
+
+    /// Tests that the account at `create_with_seed(funding, seed, owner)` is created.
+    #[test]
+    fn create_account_with_seed_self_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        let funding = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let seed = "self_base";
+        let new_account = Pubkey::create_with_seed(&funding, seed, &owner).unwrap();
+
+        let (instruction, accounts) =
+            create(&mollusk, program_id, funding, new_account, seed, 32, owner);
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let created = result.get_account(&new_account).unwrap();
+        assert_eq!(created.data().len(), 32);
+        assert_eq!(created.owner(), &owner);
+    }
+
+    /// Tests that an account derived from another base is rejected by the system program.
+    #[test]
+    fn create_account_with_seed_self_other_base_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        let funding = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let seed = "self_base";
+        let new_account = Pubkey::create_with_seed(&Pubkey::new_unique(), seed, &owner).unwrap();
+
+        let (instruction, accounts) =
+            create(&mollusk, program_id, funding, new_account, seed, 32, owner);
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            result.program_result.is_err(),
+            "An address derived from another base should be rejected",
+        );
+    }
+}
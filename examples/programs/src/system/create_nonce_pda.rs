@@ -0,0 +1,208 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::{CreateAccount, InitializeNonceAccount};
+
+use crate::common::{guards::SYSTEM_PROGRAM_ID, ix::read_pubkey};
+use crate::pda_signer;
+
+/// The size of a nonce account.
+pub const NONCE_ACCOUNT_LEN: u64 = 80;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Extract the nonce authority from the data.
+    let authority = read_pubkey(data, 0)?;
+
+    // Process the create nonce PDA instruction.
+    process_create_nonce_pda(program_id, accounts, &authority)
+}
+
+/// Processes the create nonce PDA instruction.
+///
+/// The nonce account address is derived from `[b"nonce_account"]` and this program's id, so
+/// the program can recreate it without storing it. The account is created with
+/// `NONCE_ACCOUNT_LEN` bytes, owned by the system program and signed for with the PDA seeds,
+/// then initialized with `authority` as its nonce authority.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program the nonce account is derived from.
+/// - `accounts`: The accounts required for the instruction.
+/// - `authority`: The public key of the entity authorized to manage the nonce account.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The funding account.
+/// 1. `[WRITE]` The nonce account PDA.
+/// 2. `[]` The recent blockhashes sysvar.
+/// 3. `[]` The rent sysvar.
+/// 4. `[]` The system program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_create_nonce_pda(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &Pubkey, // Pubkey of the nonce authority.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [funding_account, nonce_account, recent_blockhashes_sysvar, rent_sysvar, _system_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the funding account is a signer.
+    if !funding_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the nonce account is the expected PDA.
+    let (address, bump) = find_program_address(&[b"nonce_account"], program_id);
+    if nonce_account.key() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Create the nonce account, signing for the PDA.
+    CreateAccount {
+        from: funding_account,
+        to: nonce_account,
+        lamports: Rent::get()?.minimum_balance(NONCE_ACCOUNT_LEN as usize),
+        space: NONCE_ACCOUNT_LEN,
+        owner: &SYSTEM_PROGRAM_ID,
+    }
+    .invoke_signed(&pda_signer!(b"nonce_account", &[bump]))?;
+
+    // Initialize the nonce.
+    InitializeNonceAccount {
+        account: nonce_account,
+        recent_blockhashes_sysvar,
+        rent_sysvar,
+        authority,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NONCE_ACCOUNT_LEN;
+    use crate::test_utils::metas;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        account_utils::StateMut,
+        hash::Hash,
+        instruction::Instruction,
+        nonce::state::{State, Versions},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds the creation of the nonce account at `nonce_account`.
+    fn create(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        nonce_account: Pubkey,
+        authority: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let funding = Pubkey::new_unique();
+        let recent_blockhashes_sysvar = solana_sdk::sysvar::recent_blockhashes::ID;
+        let (rent_sysvar, rent_sysvar_account) = mollusk.sysvars.keyed_account_for_rent_sysvar();
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        // Populate the recent blockhashes sysvar with a single entry.
+        let mut sysvar_account = AccountSharedData::new(1_000_000_000u64, 0, &solana_sdk::sysvar::ID);
+        let mut sysvar_data = 1u64.to_le_bytes().to_vec();
+        sysvar_data.extend_from_slice(Hash::new_unique().as_ref());
+        sysvar_data.extend_from_slice(&5_000u64.to_le_bytes());
+        sysvar_account.set_data_from_slice(&sysvar_data);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            authority.as_ref(),
+            metas![
+                funding => (funding, true, true),
+                nonce_account => (nonce_account, false, true),
+                recent_blockhashes_sysvar => (recent_blockhashes_sysvar, false, false),
+                rent_sysvar => (rent_sysvar, false, false),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (funding, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (nonce_account, AccountSharedData::default()),
+            (recent_blockhashes_sysvar, sysvar_account),
+            (rent_sysvar, rent_sysvar_account),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts)
+    }
+
+    /// Tests that the PDA derived off-chain is created and initialized with the authority.
+    #[test]
+    fn create_nonce_pda_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (nonce_account, _) = Pubkey::find_program_address(&[b"nonce_account"], &program_id);
+        let authority = Pubkey::new_unique();
+        let (instruction, accounts) = create(&mollusk, program_id, nonce_account, authority);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let created = result.get_account(&nonce_account).unwrap();
+        assert_eq!(created.data().len(), NONCE_ACCOUNT_LEN as usize);
+        assert_eq!(created.owner(), &solana_sdk::system_program::ID);
+
+        let versions: Versions = created.state().unwrap();
+        match versions.state() {
+            State::Initialized(data) => assert_eq!(data.authority, authority),
+            State::Uninitialized => panic!("Nonce account is not initialized"),
+        }
+    }
+
+    /// Tests that an account other than the PDA is rejected with `InvalidSeeds`.
+    #[test]
+    fn create_nonce_pda_wrong_address_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts) =
+            create(&mollusk, program_id, Pubkey::new_unique(), Pubkey::new_unique());
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidSeeds)],
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::common::guards::{checked_lamport_move, require_owned_by_program};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the lamports.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the lamports to move from the data.
+    let lamports = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the direct lamport move instruction.
+    process_direct_lamport_move(program_id, accounts, lamports)
+}
+
+/// Processes the direct lamport move instruction.
+///
+/// The system program can only debit accounts it owns, so lamports held by program-owned
+/// accounts (e.g. PDAs) cannot be moved with a `Transfer` CPI. The owning program may instead
+/// edit the balances directly; the runtime checks that the total is preserved and that only
+/// accounts owned by the program were debited. Both accounts are required to be owned by
+/// this program, which keeps the example to the PDA-to-PDA case.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own both accounts.
+/// - `accounts`: The accounts required for the instruction.
+/// - `lamports`: The number of lamports to move.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The source account, owned by this program.
+/// 1. `[WRITE]` The destination account, owned by this program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_direct_lamport_move(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lamports: u64,
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [from_account, to_account] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure both accounts are writable.
+    if !from_account.is_writable() || !to_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure both accounts are owned by this program.
+    require_owned_by_program(from_account, program_id)?;
+    require_owned_by_program(to_account, program_id)?;
+
+    // Move the lamports, failing instead of wrapping on either side.
+    let mut from_lamports = from_account.try_borrow_mut_lamports()?;
+    let mut to_lamports = to_account.try_borrow_mut_lamports()?;
+    (*from_lamports, *to_lamports) = checked_lamport_move(*from_lamports, *to_lamports, lamports)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a move of `lamports` between two accounts owned by `owner`.
+    fn direct_move(
+        program_id: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &lamports.to_le_bytes(),
+            metas![
+                from => (from, false, true),
+                to => (to, false, true),
+            ],
+        );
+
+        let accounts = vec![
+            (from, AccountSharedData::new(1_000_000, 0, &owner)),
+            (to, AccountSharedData::new(500_000, 0, &owner)),
+        ];
+
+        (instruction, accounts, from, to)
+    }
+
+    /// Tests that lamports move between program-owned accounts without a CPI.
+    #[test]
+    fn direct_lamport_move_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, from, to) = direct_move(program_id, program_id, 300_000);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        assert_eq!(result.get_account(&from).unwrap().lamports(), 700_000);
+        assert_eq!(result.get_account(&to).unwrap().lamports(), 800_000);
+    }
+
+    /// Tests that moving more lamports than the source holds fails instead of wrapping.
+    #[test]
+    fn direct_lamport_move_underflow_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = direct_move(program_id, program_id, 1_000_001);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::ArithmeticOverflow)],
+        );
+    }
+
+    /// Tests that accounts owned by another program are rejected with `InvalidAccountOwner`.
+    #[test]
+    fn direct_lamport_move_not_program_owned_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) =
+            direct_move(program_id, solana_sdk::system_program::ID, 300_000);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountOwner)],
+        );
+    }
+}
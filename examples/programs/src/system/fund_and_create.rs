@@ -0,0 +1,184 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::common::ix::read_pubkey;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Validate the length of the instruction data.
+    if data.len() < 40 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the space and the owner of the new account from the data.
+    let space = unsafe { *(data.as_ptr() as *const u64) };
+    let owner = read_pubkey(data, 8)?;
+
+    // Process the fund and create instruction.
+    process_fund_and_create(accounts, space, &owner)
+}
+
+/// Processes the fund and create instruction.
+///
+/// The new account is funded with the rent-exempt minimum for `space` bytes. Before the CPI,
+/// the funder's balance is checked to cover it, so an underfunded funder fails with a log
+/// naming the shortfall instead of an opaque error from the system program. The transaction
+/// fee is debited from the fee payer before the program runs, so only rent is checked here.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `space`: The number of bytes to allocate for the new account.
+/// - `owner`: The program that will own the new account.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The funding account.
+/// 1. `[WRITE, SIGNER]` The new account to be created.
+/// 2. `[]` The system program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_fund_and_create(
+    accounts: &[AccountInfo],
+    space: u64,     // Number of bytes to allocate for the new account.
+    owner: &Pubkey, // Pubkey of the program that will own the new account.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [funding_account, new_account, _system_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the funding account and new account are signers.
+    if !funding_account.is_signer() || !new_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the funder can cover the rent-exempt minimum of the new account.
+    let lamports = Rent::get()?.minimum_balance(space as usize);
+    if funding_account.lamports() < lamports {
+        msg!(&format!(
+            "Funder holds {} lamports, {lamports} are needed for rent",
+            funding_account.lamports()
+        ));
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    // Create the account.
+    CreateAccount {
+        from: funding_account,
+        to: new_account,
+        lamports,
+        space,
+        owner,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds the creation of a 16-byte account, funded by an account holding `funds`.
+    fn fund_and_create(
+        program_id: Pubkey,
+        funds: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let funding = Pubkey::new_unique();
+        let new_account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        // Encode `[space][owner]`.
+        let mut data = 16_u64.to_le_bytes().to_vec();
+        data.extend_from_slice(owner.as_ref());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                funding => (funding, true, true),
+                new_account => (new_account, true, true),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (funding, AccountSharedData::new(funds, 0, &solana_sdk::system_program::ID)),
+            (new_account, AccountSharedData::default()),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts, new_account, owner)
+    }
+
+    /// Tests that a funder holding exactly the rent creates a rent-exempt account.
+    #[test]
+    fn fund_and_create_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let rent = mollusk.sysvars.rent.minimum_balance(16);
+        let (instruction, accounts, new_account, owner) = fund_and_create(program_id, rent);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let created = result.get_account(&new_account).unwrap();
+        assert_eq!(created.lamports(), rent);
+        assert_eq!(created.data().len(), 16);
+        assert_eq!(created.owner(), &owner);
+    }
+
+    /// Tests that an underfunded funder is rejected before the CPI.
+    #[test]
+    fn fund_and_create_underfunded_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let rent = mollusk.sysvars.rent.minimum_balance(16);
+        let (instruction, accounts, ..) = fund_and_create(program_id, rent - 1);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InsufficientFunds)],
+        );
+    }
+}
@@ -9,6 +9,8 @@ use pinocchio::{
 
 use pinocchio_system::instructions::InitializeNonceAccount;
 
+use crate::common::ix::read_pubkey;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -35,7 +37,7 @@ pub fn process_instruction(
     }
 
     // Extract the authority public key from the data.
-    let authority = unsafe { *(data.as_ptr() as *const Pubkey) };
+    let authority = read_pubkey(data, 0)?;
 
     // Extract the bump seed from the data.
     let bump: [u8; 1] = unsafe { *(data.as_ptr().add(32) as *const [u8; 1]) };
@@ -58,6 +60,7 @@ pub fn process_instruction(
 /// 0. `[WRITE]` The Nonce account.
 /// 1. `[]` The recent blockhashes sysvar.
 /// 2. `[]` The rent sysvar.
+/// 3. `[]` The system program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -67,13 +70,15 @@ pub fn process_initialize_nonce_account<'a>(
     bump: [u8; 1],
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [nonce_account, recent_blockhashes_sysvar, rent_sysvar] = accounts else {
+    let [nonce_account, recent_blockhashes_sysvar, rent_sysvar, _system_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     // Ensure that the nonce account is writable.
-    assert!(nonce_account.is_writable());
+    if !nonce_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
 
     // Construct the `InitializeNonceAccount` instruction.
     let initialize_nonce_account_instruction = InitializeNonceAccount {
@@ -93,4 +98,108 @@ pub fn process_initialize_nonce_account<'a>(
     initialize_nonce_account_instruction.invoke_signed(&signer)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, WritableAccount},
+        account_utils::StateMut,
+        hash::Hash,
+        instruction::Instruction,
+        nonce::state::{State, Versions},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds the initialization of an allocated nonce account, writable or not.
+    fn initialize(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        authority: Pubkey,
+        writable: bool,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let nonce_account = Pubkey::new_unique();
+        let recent_blockhashes_sysvar = solana_sdk::sysvar::recent_blockhashes::ID;
+        let (rent_sysvar, rent_sysvar_account) = mollusk.sysvars.keyed_account_for_rent_sysvar();
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        // Populate the recent blockhashes sysvar with a single entry.
+        let mut sysvar_account = AccountSharedData::new(1_000_000_000u64, 0, &solana_sdk::sysvar::ID);
+        let mut sysvar_data = 1u64.to_le_bytes().to_vec();
+        sysvar_data.extend_from_slice(Hash::new_unique().as_ref());
+        sysvar_data.extend_from_slice(&5_000u64.to_le_bytes());
+        sysvar_account.set_data_from_slice(&sysvar_data);
+
+        // Encode `[authority][bump]`.
+        let mut data = authority.to_bytes().to_vec();
+        data.push(0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                nonce_account => (nonce_account, false, writable),
+                recent_blockhashes_sysvar => (recent_blockhashes_sysvar, false, false),
+                rent_sysvar => (rent_sysvar, false, false),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (
+                nonce_account,
+                AccountSharedData::new(
+                    mollusk.sysvars.rent.minimum_balance(State::size()),
+                    State::size(),
+                    &solana_sdk::system_program::ID,
+                ),
+            ),
+            (recent_blockhashes_sysvar, sysvar_account),
+            (rent_sysvar, rent_sysvar_account),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts, nonce_account)
+    }
+
+    /// Tests that a writable nonce account is initialized with the authority.
+    #[test]
+    fn initialize_nonce_account_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let authority = Pubkey::new_unique();
+        let (instruction, accounts, nonce_account) = initialize(&mollusk, program_id, authority, true);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let versions: Versions = result.get_account(&nonce_account).unwrap().state().unwrap();
+        match versions.state() {
+            State::Initialized(data) => assert_eq!(data.authority, authority),
+            State::Uninitialized => panic!("Nonce account is not initialized"),
+        }
+    }
+
+    /// Tests that a read-only nonce account is rejected with `InvalidAccountData`.
+    #[test]
+    fn initialize_nonce_account_not_writable_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) =
+            initialize(&mollusk, program_id, Pubkey::new_unique(), false);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
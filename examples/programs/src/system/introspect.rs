@@ -0,0 +1,193 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    entrypoint,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+// The address of the Instructions sysvar, decoded from a base58 string.
+pub const INSTRUCTIONS_SYSVAR_ID: Pubkey =
+    five8_const::decode_32_const("Sysvar1nstructions1111111111111111111111111");
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the introspection instruction.
+    process_introspect(accounts)
+}
+
+/// Reads the instruction count and the current index from the Instructions sysvar data.
+///
+/// The sysvar starts with a little-endian `u16` holding the number of instructions in the
+/// transaction and ends with a little-endian `u16` holding the index of the instruction
+/// currently executing.
+///
+/// ### Parameters:
+/// - `data`: The raw data of the Instructions sysvar account.
+///
+/// ### Returns:
+/// - `Result<(u16, u16), ProgramError>`: The `(count, current_index)` pair.
+pub fn read_instructions_header(data: &[u8]) -> Result<(u16, u16), ProgramError> {
+    // The sysvar holds at least the count and the current index.
+    if data.len() < 4 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let count = u16::from_le_bytes([data[0], data[1]]);
+    let current_index = u16::from_le_bytes([data[data.len() - 2], data[data.len() - 1]]);
+
+    // The current instruction must be one of the instructions in the transaction.
+    if current_index >= count {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok((count, current_index))
+}
+
+/// Processes the introspection instruction.
+///
+/// This function loads the Instructions sysvar, logs the position of the current
+/// instruction within the transaction, and sets the instruction count as return data.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[]` The Instructions sysvar.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_introspect(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [instructions_sysvar] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the account is the Instructions sysvar.
+    if instructions_sysvar.key() != &INSTRUCTIONS_SYSVAR_ID {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    // Read the instruction count and the current index.
+    let (count, current_index) = {
+        let data = instructions_sysvar.try_borrow_data()?;
+        read_instructions_header(&data)?
+    };
+
+    msg!(&format!("Instruction {} of {}", current_index + 1, count));
+
+    // Expose the count to the caller.
+    set_return_data(&count.to_le_bytes());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_instructions_header;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, WritableAccount},
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        sysvar::instructions::{
+            construct_instructions_data, store_current_index, BorrowedAccountMeta,
+            BorrowedInstruction,
+        },
+    };
+
+    /// Serializes the Instructions sysvar for a transaction, marking `current` as executing.
+    fn instructions_sysvar_data(instructions: &[Instruction], current: u16) -> Vec<u8> {
+        let borrowed = instructions
+            .iter()
+            .map(|instruction| BorrowedInstruction {
+                program_id: &instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect(),
+                data: &instruction.data,
+            })
+            .collect::<Vec<_>>();
+
+        let mut data = construct_instructions_data(&borrowed);
+        store_current_index(&mut data, current);
+        data
+    }
+
+    /// Tests the header parser against the runtime serialization.
+    #[test]
+    fn read_instructions_header_test() {
+        let program_id = Pubkey::new_unique();
+        let instructions = [
+            Instruction::new_with_bytes(program_id, &[1], vec![]),
+            Instruction::new_with_bytes(program_id, &[2], vec![]),
+            Instruction::new_with_bytes(program_id, &[3], vec![]),
+        ];
+
+        let data = instructions_sysvar_data(&instructions, 2);
+        assert_eq!(read_instructions_header(&data).unwrap(), (3, 2));
+
+        // Truncated data is rejected.
+        assert!(read_instructions_header(&data[..3]).is_err());
+    }
+
+    /// Tests that the program reports two instructions in a two-instruction transaction.
+    #[test]
+    fn introspect_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        let sysvar = solana_sdk::sysvar::instructions::ID;
+
+        // The instruction under test is the second of two instructions.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![AccountMeta::new_readonly(sysvar, false)],
+        );
+        let transaction = [
+            Instruction::new_with_bytes(solana_sdk::system_program::ID, &[], vec![]),
+            instruction.clone(),
+        ];
+
+        let mut sysvar_account = AccountSharedData::new(1_000_000_000, 0, &solana_sdk::sysvar::ID);
+        sysvar_account.set_data_from_slice(&instructions_sysvar_data(&transaction, 1));
+
+        let result = mollusk.process_instruction(&instruction, &vec![(sysvar, sysvar_account)]);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The program read a count of two.
+        assert_eq!(result.return_data, 2u16.to_le_bytes().to_vec());
+    }
+}
@@ -4,10 +4,18 @@ pub mod allocate_with_seed;
 pub mod assign;
 pub mod assign_with_seed;
 pub mod authorize_nonce_account;
+pub mod counter;
 pub mod create_account;
 pub mod create_account_with_seed;
+pub mod create_account_with_seed_self;
+pub mod create_nonce_pda;
+pub mod direct_lamport_move;
+pub mod fund_and_create;
 pub mod initialize_nonce_account;
+pub mod introspect;
+pub mod setup_with_seed_account;
 pub mod transfer_lamports;
+pub mod transfer_max_lamports;
 pub mod transfer_with_seed;
 pub mod update_nonce_account;
 pub mod withdraw_nonce_account;
@@ -18,10 +26,18 @@ pub mod withdraw_nonce_account;
 // pub use assign::*;
 // pub use assign_with_seed::*;
 // pub use authorize_nonce_account::*;
+// pub use counter::*;
 // pub use create_account::*;
 // pub use create_account_with_seed::*;
+// pub use create_account_with_seed_self::*;
+// pub use create_nonce_pda::*;
+// pub use direct_lamport_move::*;
+// pub use fund_and_create::*;
 // pub use initialize_nonce_account::*;
+// pub use introspect::*;
+// pub use setup_with_seed_account::*;
 // pub use transfer_lamports::*;
+// pub use transfer_max_lamports::*;
 // pub use transfer_with_seed::*;
 // pub use update_nonce_account::*;
 // pub use withdraw_nonce_account::*;
\ No newline at end of file
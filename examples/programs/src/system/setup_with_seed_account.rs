@@ -0,0 +1,214 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::{self, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::{AllocateWithSeed, AssignWithSeed, Transfer};
+
+use crate::common::ix::read_seed;
+use crate::common::guards::assert_account_rent_exempt;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data contains at least the seed length.
+    if data.is_empty() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the seed length and ensure the rest of the payload fits.
+    let seed_len = data[0] as usize;
+    if data.len() < 1 + seed_len + 8 + 32 + 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the seed string.
+    let seed = read_seed(data, 0)?;
+
+    // Extract `space` (u64) from the next 8 bytes after the seed.
+    let space_offset = 1 + seed_len;
+    let space = unsafe { *(data.as_ptr().add(space_offset) as *const u64) };
+
+    // Extract `owner` (Pubkey) from the next 32 bytes after `space`.
+    let owner_offset = space_offset + 8;
+    let owner = unsafe { *(data.as_ptr().add(owner_offset) as *const Pubkey) };
+
+    // Extract `lamports` (u64) from the last 8 bytes.
+    let lamports_offset = owner_offset + 32;
+    let lamports = unsafe { *(data.as_ptr().add(lamports_offset) as *const u64) };
+
+    // Process the setup instruction.
+    process_setup_with_seed_account(accounts, seed, space, &owner, lamports)
+}
+
+/// Processes the setup of an account derived with a seed.
+///
+/// This function fully initializes a with-seed account in three CPIs: `AllocateWithSeed`
+/// sizes it, `AssignWithSeed` hands it to its owner program and `Transfer` funds it.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `seed`: The seed used to derive the account's address.
+/// - `space`: The number of bytes to allocate.
+/// - `owner`: The program that will own the account.
+/// - `lamports`: The lamports to fund the account with, usually its rent-exempt minimum.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The funding account.
+/// 1. `[WRITE]` The account derived from the base, the seed and the owner.
+/// 2. `[SIGNER]` The base account used to derive the account.
+/// 3. `[]` The system program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_setup_with_seed_account(
+    accounts: &[AccountInfo],
+    seed: &str,     // String used along with the base public key to derive the account's address.
+    space: u64,     // The number of bytes to allocate for the account.
+    owner: &Pubkey, // The program that will own the account.
+    lamports: u64,  // The lamports to fund the account with.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [funding_account, derived_account, base_account, _system_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the funding and base accounts are signers.
+    if !funding_account.is_signer() || !base_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the funding and derived accounts are writable.
+    if !funding_account.is_writable() || !derived_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Validate the seed length.
+    if seed.len() > pubkey::MAX_SEED_LEN {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Allocate the account's data.
+    AllocateWithSeed {
+        account: derived_account,
+        base: base_account,
+        seed,
+        space,
+        owner,
+    }
+    .invoke()?;
+
+    // Assign the account to its owner program.
+    AssignWithSeed {
+        account: derived_account,
+        base: base_account,
+        seed,
+        owner,
+    }
+    .invoke()?;
+
+    // Fund the account.
+    Transfer {
+        from: funding_account,
+        to: derived_account,
+        lamports,
+    }
+    .invoke()?;
+
+    // Ensure the account was funded to rent exemption.
+    assert_account_rent_exempt(derived_account, &Rent::get()?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that the account ends up sized, owned and funded.
+    #[test]
+    fn setup_with_seed_account_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the system program and Mollusk environment.
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // Derive the account from the base, the seed and the new owner.
+        let funding = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let seed = "vault";
+        let derived = Pubkey::create_with_seed(&base, seed, &owner).unwrap();
+
+        // Fund the account to rent exemption for its size.
+        let space = 64_u64;
+        let lamports = mollusk.sysvars.rent.minimum_balance(space as usize);
+
+        // Encode `[seed_len][seed][space][owner][lamports]`.
+        let mut data = vec![seed.len() as u8];
+        data.extend_from_slice(seed.as_bytes());
+        data.extend_from_slice(&space.to_le_bytes());
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(&lamports.to_le_bytes());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                funding => (funding, true, true),
+                derived => (derived, false, true),
+                base => (base, true, false),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (funding, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (derived, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (base, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (system_program, system_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The account has its final size, owner and balance.
+        let account = result.get_account(&derived).unwrap();
+        assert_eq!(account.data().len(), space as usize);
+        assert_eq!(account.owner(), &owner);
+        assert_eq!(account.lamports(), lamports);
+    }
+}
@@ -0,0 +1,186 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::Transfer;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the transfer max lamports instruction.
+    process_transfer_max_lamports(accounts)
+}
+
+/// Processes the transfer max lamports instruction.
+///
+/// This function transfers every lamport above the rent-exempt minimum of the source
+/// account, so the source stays alive after the transfer.
+///
+/// The system program only transfers from accounts that carry no data, so the source must
+/// be a data-less system account and its minimum is the one of a zero-length account.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The source account, a system account without data.
+/// 1. `[WRITE]` The destination account.
+/// 2. `[]` The system program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_max_lamports(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [from_account, to_account, _system_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure that the 'from' account is writable and a signer.
+    if !from_account.is_writable() || !from_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure that the 'to' account is writable.
+    if !to_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the source holds no data, which the system program would reject anyway.
+    if from_account.data_len() > 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Compute the amount the source must keep to remain rent-exempt.
+    let keep = Rent::get()?.minimum_balance(0);
+
+    // Everything above the rent-exempt minimum can be sent.
+    let lamports = from_account
+        .lamports()
+        .checked_sub(keep)
+        .ok_or(ProgramError::InsufficientFunds)?;
+
+    // Construct and invoke the `Transfer` instruction.
+    Transfer {
+        from: from_account,
+        to: to_account,
+        lamports,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that the source keeps exactly its rent-exempt minimum.
+    #[test]
+    fn transfer_max_lamports_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+
+        let starting_balance = 5_000_000_000u64;
+        let rent_exempt_minimum = mollusk.sysvars.rent.minimum_balance(0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![
+                AccountMeta::new(from, true),
+                AccountMeta::new(to, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (from, AccountSharedData::new(starting_balance, 0, &solana_sdk::system_program::ID)),
+                (to, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (system_program, system_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The source keeps the rent-exempt minimum and the rest reaches the destination.
+        assert_eq!(result.get_account(&from).unwrap().lamports(), rent_exempt_minimum);
+        assert_eq!(
+            result.get_account(&to).unwrap().lamports(),
+            starting_balance - rent_exempt_minimum
+        );
+    }
+
+    /// Tests that a source account holding data is rejected before the transfer.
+    #[test]
+    fn transfer_max_lamports_source_with_data_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![
+                AccountMeta::new(from, true),
+                AccountMeta::new(to, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (from, AccountSharedData::new(5_000_000_000, 16, &solana_sdk::system_program::ID)),
+                (to, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (system_program, system_program_account),
+            ],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
@@ -9,6 +9,8 @@ use pinocchio::{
 
 use pinocchio_system::instructions::TransferWithSeed;
 
+use crate::common::ix::read_seed;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -46,9 +48,7 @@ pub fn process_instruction(
     }
 
     // Extract the seed from the data.
-    let seed = unsafe {
-        std::str::from_utf8_unchecked(&data[9..9 + seed_len])
-    };
+    let seed = read_seed(data, 8)?;
 
     // Extract the owner public key from the data.
     let owner_offset = 9 + seed_len;
@@ -52,6 +52,7 @@ pub fn process_instruction(
 ///
 /// ### Accounts:
 /// 0. `[WRITE]` The Nonce account.
+/// 1. `[]` The system program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -60,13 +61,15 @@ pub fn process_update_nonce_account<'a>(
     bump: [u8; 1],  
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [nonce_account] = accounts else {
+    let [nonce_account, _system_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     // Ensure that the 'nonce_account' is writable.
-    assert!(nonce_account.is_writable());
+    if !nonce_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
 
     // Construct the `UpdateNonceAccount` instruction.
     let update_nonce_instruction = UpdateNonceAccount {
@@ -81,4 +84,93 @@ pub fn process_update_nonce_account<'a>(
     update_nonce_instruction.invoke_signed(&signer)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::AccountSharedData,
+        account_utils::StateMut,
+        hash::Hash,
+        instruction::Instruction,
+        nonce::state::{Data, DurableNonce, State, Versions},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds the instruction and a legacy nonce account, writable or not.
+    fn update(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        writable: bool,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let nonce_account = Pubkey::new_unique();
+
+        // A nonce account still stored with the legacy version.
+        let nonce_state = Versions::Legacy(Box::new(State::Initialized(Data::new(
+            Pubkey::new_unique(),
+            DurableNonce::from_blockhash(&Hash::new_unique()),
+            5_000,
+        ))));
+        let nonce_account_data = AccountSharedData::new_data(
+            mollusk.sysvars.rent.minimum_balance(State::size()),
+            &nonce_state,
+            &solana_sdk::system_program::ID,
+        )
+        .unwrap();
+
+        let (_, bump) = Pubkey::find_program_address(&[b"seeds"], &program_id);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[bump, 0, 0, 0, 0, 0, 0, 0],
+            metas![
+                nonce => (nonce_account, false, writable),
+                system_program => (system_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (nonce_account, nonce_account_data),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts, nonce_account)
+    }
+
+    /// Tests that a writable legacy nonce account is upgraded to the current version.
+    #[test]
+    fn update_nonce_account_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, nonce_account) = update(&mollusk, program_id, true);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let versions: Versions = result.get_account(&nonce_account).unwrap().state().unwrap();
+        assert!(matches!(versions, Versions::Current(_)));
+    }
+
+    /// Tests that a read-only nonce account returns an error instead of aborting.
+    #[test]
+    fn update_nonce_account_read_only_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = update(&mollusk, program_id, false);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
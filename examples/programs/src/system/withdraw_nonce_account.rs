@@ -4,11 +4,14 @@ use pinocchio::{
     program_error::ProgramError,
     instruction::{Signer, Seed},
     pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 
 use pinocchio_system::instructions::WithdrawNonceAccount;
 
+use crate::common::error::ExampleError;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -45,7 +48,8 @@ pub fn process_instruction(
 /// Processes the `WithdrawNonceAccount` instruction.
 ///
 /// This function handles the logic for withdrawing lamports from a nonce account. It validates
-/// the accounts and signers, constructs the instruction, and invokes it.
+/// the accounts and signers, constructs the instruction, and invokes it. Withdrawals that
+/// would leave the nonce account below its rent-exempt minimum are rejected up front.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
@@ -58,6 +62,7 @@ pub fn process_instruction(
 /// 2. `[]` The recent blockhashes sysvar.
 /// 3. `[]` The rent sysvar.
 /// 4. `[SIGNER]` The Nonce authority.
+/// 5. `[]` The system program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -67,7 +72,7 @@ pub fn process_withdraw_nonce_account<'a>(
     lamports_to_withdraw: u64,
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [nonce_account, recipient_account, recent_blockhashes_sysvar, rent_sysvar, nonce_authority] = accounts else {
+    let [nonce_account, recipient_account, recent_blockhashes_sysvar, rent_sysvar, nonce_authority, _system_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -82,6 +87,15 @@ pub fn process_withdraw_nonce_account<'a>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Compute the most the nonce account can give while staying rent-exempt.
+    let keep = Rent::get()?.minimum_balance(nonce_account.data_len());
+    let max_withdrawable = nonce_account.lamports().saturating_sub(keep);
+
+    // Ensure the withdrawal does not eat into the rent-exempt minimum.
+    if lamports_to_withdraw > max_withdrawable {
+        return Err(ExampleError::ExceedsWithdrawable.into());
+    }
+
     // Construct the `WithdrawNonceAccount` instruction.
     let withdraw_nonce_instruction = WithdrawNonceAccount {
         account: nonce_account,
@@ -100,4 +114,116 @@ pub fn process_withdraw_nonce_account<'a>(
     withdraw_nonce_instruction.invoke_signed(&signer)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::ExampleError;
+    use crate::test_utils::assert_custom_error;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        nonce::state::{Data, DurableNonce, State, Versions},
+        pubkey::Pubkey,
+    };
+
+    /// The lamports held by the nonce account above its rent-exempt minimum.
+    const EXCESS: u64 = 1_000_000;
+
+    /// Builds a withdrawal of `lamports` from a nonce account holding `EXCESS` lamports above
+    /// its rent-exempt minimum.
+    fn withdraw(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        lamports: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let nonce_account = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let recent_blockhashes_sysvar = solana_sdk::sysvar::recent_blockhashes::ID;
+        let (rent_sysvar, rent_sysvar_account) = mollusk.sysvars.keyed_account_for_rent_sysvar();
+        let (nonce_authority, bump) = Pubkey::find_program_address(&[b"nonce_authority"], &program_id);
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        // Create a nonce account controlled by the authority PDA.
+        let nonce_state = Versions::new(State::Initialized(Data::new(
+            nonce_authority,
+            DurableNonce::from_blockhash(&Hash::new_unique()),
+            5_000,
+        )));
+        let nonce_account_data = AccountSharedData::new_data(
+            mollusk.sysvars.rent.minimum_balance(State::size()) + EXCESS,
+            &nonce_state,
+            &solana_sdk::system_program::ID,
+        )
+        .unwrap();
+
+        // Populate the recent blockhashes sysvar with a single entry.
+        let mut sysvar_account = AccountSharedData::new(1_000_000_000u64, 0, &solana_sdk::sysvar::ID);
+        let mut sysvar_data = 1u64.to_le_bytes().to_vec();
+        sysvar_data.extend_from_slice(Hash::new_unique().as_ref());
+        sysvar_data.extend_from_slice(&5_000u64.to_le_bytes());
+        sysvar_account.set_data_from_slice(&sysvar_data);
+
+        let mut data = vec![bump];
+        data.extend_from_slice(&lamports.to_le_bytes());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(nonce_account, false),
+                AccountMeta::new(recipient, false),
+                AccountMeta::new_readonly(recent_blockhashes_sysvar, false),
+                AccountMeta::new_readonly(rent_sysvar, false),
+                AccountMeta::new_readonly(nonce_authority, true),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        let accounts = vec![
+            (nonce_account, nonce_account_data),
+            (recipient, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+            (recent_blockhashes_sysvar, sysvar_account),
+            (rent_sysvar, rent_sysvar_account),
+            (nonce_authority, AccountSharedData::default()),
+            (system_program, system_program_account),
+        ];
+
+        (instruction, accounts, nonce_account, recipient)
+    }
+
+    /// Tests that the whole amount above the rent-exempt minimum can be withdrawn.
+    #[test]
+    fn withdraw_nonce_account_max_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, nonce_account, recipient) =
+            withdraw(&mollusk, program_id, EXCESS);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        assert_eq!(result.get_account(&recipient).unwrap().lamports(), EXCESS);
+        assert_eq!(
+            result.get_account(&nonce_account).unwrap().lamports(),
+            mollusk.sysvars.rent.minimum_balance(State::size())
+        );
+    }
+
+    /// Tests that withdrawing one lamport more than the maximum is rejected.
+    #[test]
+    fn withdraw_nonce_account_exceeds_max_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = withdraw(&mollusk, program_id, EXCESS + 1);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::ExceedsWithdrawable as u32);
+    }
+}
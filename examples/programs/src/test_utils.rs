@@ -0,0 +1,202 @@
+//! Helpers shared by the Mollusk tests.
+
+use mollusk_svm::{
+    result::{InstructionResult, ProgramResult},
+    Mollusk,
+};
+use solana_sdk::{
+    account::{AccountSharedData, WritableAccount},
+    program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token::state::AccountState;
+
+/// Upper bound on the compute units of a successful `transfer`.
+///
+/// The baseline is dominated by the SPL Token `Transfer` CPI (roughly 4_500 units including
+/// the invoke overhead), with a few hundred units for the role and mint checks and the
+/// entrypoint. It leaves headroom for toolchain noise while still catching a change that, for
+/// example, adds an extra CPI or a `find_program_address`. Raise it only together with the
+/// change that justifies it.
+pub(crate) const TRANSFER_CU_BASELINE: u64 = 7_500;
+
+/// Upper bound on the compute units of a successful `mint_to`.
+///
+/// The SPL Token `MintTo` CPI accounts for roughly 4_500 units including the invoke overhead,
+/// and signing with the mint authority PDA adds about 1_500 for `create_program_address`.
+pub(crate) const MINT_TO_CU_BASELINE: u64 = 9_000;
+
+/// Builds a `Vec<AccountMeta>` from labeled `(key, is_signer, is_writable)` tuples.
+///
+/// The label documents the role of each account and is otherwise ignored:
+///
+/// ```ignore
+/// let metas = metas![
+///     sender => (sender_ta, false, true),
+///     authority => (signer, true, false),
+/// ];
+/// ```
+macro_rules! metas {
+    ($($label:ident => ($key:expr, $is_signer:expr, $is_writable:expr)),* $(,)?) => {
+        vec![$(
+            solana_sdk::instruction::AccountMeta {
+                pubkey: $key,
+                is_signer: $is_signer,
+                is_writable: $is_writable,
+            }
+        ),*]
+    };
+}
+
+pub(crate) use metas;
+
+/// Asserts that an instruction failed with `ProgramError::Custom(code)`.
+///
+/// Checking `program_result.is_err()` alone lets a test pass when the instruction fails for
+/// an unrelated reason; pinning the code catches error-code regressions.
+///
+/// ```ignore
+/// let result = mollusk.process_instruction(&instruction, &accounts);
+/// assert_custom_error(&result, ExampleError::ZeroAmount as u32);
+/// ```
+pub(crate) fn assert_custom_error(result: &InstructionResult, code: u32) {
+    match &result.program_result {
+        ProgramResult::Failure(ProgramError::Custom(actual)) => assert_eq!(
+            *actual, code,
+            "Expected custom error {code}, got custom error {actual}",
+        ),
+        other => panic!("Expected custom error {code}, got {other:?}"),
+    }
+}
+
+/// Packs `state` into a rent-exempt account owned by the token program.
+///
+/// Use it with struct update syntax for fixtures that differ from [`token_state`] or
+/// [`mint_state`] in a single field:
+///
+/// ```ignore
+/// let frozen = packed_account(
+///     &mollusk,
+///     spl_token::state::Account { state: AccountState::Frozen, ..token_state(mint, owner, 0) },
+/// );
+/// ```
+pub(crate) fn packed_account<T: Pack>(mollusk: &Mollusk, state: T) -> AccountSharedData {
+    let mut account = AccountSharedData::new(
+        mollusk.sysvars.rent.minimum_balance(T::LEN),
+        T::LEN,
+        &spl_token::id(),
+    );
+    state.pack_into_slice(account.data_as_mut_slice());
+    account
+}
+
+/// An initialized token account holding `amount` tokens of `mint`, without delegate.
+pub(crate) fn token_state(mint: Pubkey, owner: Pubkey, amount: u64) -> spl_token::state::Account {
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+}
+
+/// An initialized mint with 6 decimals and no freeze authority.
+pub(crate) fn mint_state(mint_authority: Pubkey, supply: u64) -> spl_token::state::Mint {
+    spl_token::state::Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+}
+
+/// Creates an initialized token account holding `amount` tokens of `mint`.
+pub(crate) fn token_account(
+    mollusk: &Mollusk,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) -> AccountSharedData {
+    packed_account(mollusk, token_state(mint, owner, amount))
+}
+
+/// Creates an initialized mint whose mint authority is `mint_authority`.
+pub(crate) fn mint_account(
+    mollusk: &Mollusk,
+    mint_authority: Pubkey,
+    supply: u64,
+) -> AccountSharedData {
+    packed_account(mollusk, mint_state(mint_authority, supply))
+}
+
+/// Creates an initialized `m`-of-`signers.len()` multisig.
+pub(crate) fn multisig_account(mollusk: &Mollusk, m: u8, signers: &[Pubkey]) -> AccountSharedData {
+    let mut multisig = spl_token::state::Multisig {
+        m,
+        n: signers.len() as u8,
+        is_initialized: true,
+        ..Default::default()
+    };
+    multisig.signers[..signers.len()].copy_from_slice(signers);
+    packed_account(mollusk, multisig)
+}
+
+/// Encodes a Token-2022 mint of `supply` tokens with the `MintCloseAuthority` extension.
+///
+/// The base mint is padded to the length of a token account, followed by the account type
+/// and the extension's type, length and value.
+pub(crate) fn token_2022_mint_data(supply: u64, close_authority: Pubkey) -> Vec<u8> {
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    mint_state(Pubkey::new_unique(), supply).pack_into_slice(&mut data);
+
+    // Pad to the account type, then append the extension.
+    data.resize(spl_token::state::Account::LEN, 0);
+    data.push(1);
+    data.extend_from_slice(&3u16.to_le_bytes());
+    data.extend_from_slice(&32u16.to_le_bytes());
+    data.extend_from_slice(close_authority.as_ref());
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::metas;
+    use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+    /// Tests that the macro produces the same metas as the `AccountMeta` constructors.
+    #[test]
+    fn metas_test() {
+        let sender = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let produced = metas![
+            sender => (sender, false, true),
+            authority => (authority, true, false),
+            program => (program, false, false),
+            payer => (payer, true, true),
+        ];
+
+        assert_eq!(
+            produced,
+            vec![
+                AccountMeta::new(sender, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(program, false),
+                AccountMeta::new(payer, true),
+            ]
+        );
+
+        // An empty list is allowed.
+        let empty: Vec<AccountMeta> = metas![];
+        assert!(empty.is_empty());
+    }
+}
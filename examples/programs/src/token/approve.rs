@@ -9,6 +9,15 @@ use pinocchio::{
 
 use pinocchio_token::instructions::Approve;
 
+#[cfg(feature = "reject_zero")]
+use crate::common::guards::require_nonzero;
+
+#[cfg(feature = "strict_approve")]
+use crate::common::{
+    error::ExampleError,
+    token_state::{borrow_token_data, TokenAccount},
+};
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -47,7 +56,8 @@ pub fn process_instruction(
 /// Processes the `Approve` instruction.
 ///
 /// This function handles the logic for approving a token transfer. It validates the accounts
-/// and signers, constructs the instruction, and invokes it.
+/// and signers, constructs the instruction, and invokes it. With the `strict_approve`
+/// feature, approving more than the source account holds fails instead of succeeding.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
@@ -58,6 +68,7 @@ pub fn process_instruction(
 /// 0. `[WRITE]` The token account.
 /// 1. `[]` The delegate account.
 /// 2. `[SIGNER]` The source account owner.
+/// 3. `[]` The token program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -67,7 +78,7 @@ pub fn process_approve<'a>(
     bump: [u8; 1],      // The bump seed used for signer derivation.
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [source_account, delegate_account, authority_account] = accounts else {
+    let [source_account, delegate_account, authority_account, _token_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -82,6 +93,16 @@ pub fn process_approve<'a>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Reject zero amounts, which are almost always a client bug.
+    #[cfg(feature = "reject_zero")]
+    require_nonzero(amount)?;
+
+    // Reject approving more than the source holds, which usually hides a client bug.
+    #[cfg(feature = "strict_approve")]
+    if amount > TokenAccount::from_bytes(&borrow_token_data(source_account)?)?.amount() {
+        return Err(ExampleError::InsufficientFunds.into());
+    }
+
     // Construct the `Approve` instruction.
     let approve_instruction = Approve {
         source: source_account,
@@ -99,3 +120,114 @@ pub fn process_approve<'a>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::Instruction,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds an approval of `amount` tokens on a source account holding 1_000 tokens.
+    fn approve(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        amount: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let (authority, bump) = Pubkey::find_program_address(&[b"authority_account"], &program_id);
+        let source = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let source_account = token_account(
+            mollusk,
+            Pubkey::new_from_array([0x02; 32]),
+            authority,
+            1_000,
+        );
+
+        let mut data = amount.to_le_bytes().to_vec();
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                source => (source, false, true),
+                delegate => (delegate, false, false),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (source, source_account),
+            (delegate, AccountSharedData::default()),
+            (authority, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, source, delegate)
+    }
+
+    /// Tests that, by default, approving more than the balance succeeds, as in SPL Token.
+    #[cfg(not(feature = "strict_approve"))]
+    #[test]
+    fn approve_above_balance_permissive_test() {
+        use solana_sdk::account::ReadableAccount;
+
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, source, delegate) = approve(&mut mollusk, program_id, 2_000);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let account =
+            spl_token::state::Account::unpack(result.get_account(&source).unwrap().data()).unwrap();
+        assert_eq!(account.delegate, COption::Some(delegate));
+        assert_eq!(account.delegated_amount, 2_000);
+    }
+
+    /// Tests that, with `strict_approve`, approving more than the balance is rejected.
+    #[cfg(feature = "strict_approve")]
+    #[test]
+    fn approve_above_balance_strict_test() {
+        use crate::common::error::ExampleError;
+        use crate::test_utils::assert_custom_error;
+
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = approve(&mut mollusk, program_id, 2_000);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::InsufficientFunds as u32);
+    }
+
+    /// Tests that, with `strict_approve`, approving the whole balance is still allowed.
+    #[cfg(feature = "strict_approve")]
+    #[test]
+    fn approve_whole_balance_strict_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = approve(&mut mollusk, program_id, 1_000);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+    }
+}
@@ -9,6 +9,9 @@ use pinocchio::{
 
 use pinocchio_token::instructions::Burn;
 
+#[cfg(feature = "reject_zero")]
+use crate::common::guards::require_nonzero;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -87,6 +90,10 @@ pub fn process_burn<'a>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Reject zero amounts, which are almost always a client bug.
+    #[cfg(feature = "reject_zero")]
+    require_nonzero(amount)?;
+
     // Construct the `Burn` instruction.
     let burn_instruction = Burn {
         account: burn_account,
@@ -29,8 +29,8 @@ pub fn process_instruction(
     accounts: &[AccountInfo],
     data: &[u8],
 ) -> ProgramResult {
-    // Ensure the data length is sufficient for parsing.
-    if data.len() < 9 {
+    // Ensure the data length is sufficient for the amount, decimals and bump.
+    if data.len() < 10 {
         return Err(ProgramError::InvalidInstructionData);
     }
 
@@ -58,6 +58,7 @@ pub fn process_instruction(
 /// 0. `[WRITE]` The account to burn from.
 /// 1. `[WRITE]` The token mint.
 /// 2. `[SIGNER]` The account's owner/delegate.
+/// 3. `[]` The token program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -68,7 +69,7 @@ pub fn process_burn_checked<'a>(
     bump: [u8; 1],      // The bump seed for the authority.
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [burn_account, mint_account, authority_account] = accounts else {
+    let [burn_account, mint_account, authority_account, _token_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -106,3 +107,115 @@ pub fn process_burn_checked<'a>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, mint_account, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+    use spl_token::error::TokenError;
+
+    /// Builds a burn of `amount` tokens declared with `decimals`, out of an account holding
+    /// 1_000 tokens of a 6-decimal mint with a supply of 5_000.
+    fn burn(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let (authority, bump) = Pubkey::find_program_address(&[b"authority_account"], &program_id);
+        let account = Pubkey::new_unique();
+
+        let mint_account = mint_account(mollusk, Pubkey::new_unique(), 5_000);
+
+        let token_account = token_account(mollusk, mint, authority, 1_000);
+
+        let mut data = amount.to_le_bytes().to_vec();
+        data.push(decimals);
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                account => (account, false, true),
+                mint => (mint, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (account, token_account),
+            (mint, mint_account),
+            (authority, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, account, mint)
+    }
+
+    /// Tests that burning with matching decimals lowers both the balance and the supply.
+    #[test]
+    fn burn_checked_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, account, mint) = burn(&mut mollusk, program_id, 400, 6);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let account =
+            spl_token::state::Account::unpack(result.get_account(&account).unwrap().data()).unwrap();
+        assert_eq!(account.amount, 600);
+
+        let mint = spl_token::state::Mint::unpack(result.get_account(&mint).unwrap().data()).unwrap();
+        assert_eq!(mint.supply, 4_600);
+    }
+
+    /// Tests that decimals that differ from the mint's are rejected by the token program.
+    #[test]
+    fn burn_checked_decimals_mismatch_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = burn(&mut mollusk, program_id, 400, 9);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::Custom(
+                TokenError::MintDecimalsMismatch as u32,
+            ))],
+        );
+    }
+
+    /// Tests that burning more than the balance is rejected by the token program.
+    #[test]
+    fn burn_checked_insufficient_funds_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = burn(&mut mollusk, program_id, 1_001, 6);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::Custom(
+                TokenError::InsufficientFunds as u32,
+            ))],
+        );
+    }
+}
@@ -2,13 +2,14 @@ use pinocchio::{
     account_info::AccountInfo,
     entrypoint,
     program_error::ProgramError,
-    instruction::{Signer, Seed},
     pubkey::Pubkey,
     ProgramResult
 };
 
 use pinocchio_token::instructions::CloseAccount;
 
+use crate::pda_signer;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -89,12 +90,8 @@ pub fn process_close_account<'a>(
         authority: authority_account,
     };
 
-    // Create the seeds and signer for the instruction.
-    let seeds = [Seed::from(b"authority_account"), Seed::from(&bump)];
-    let signer = [Signer::from(&seeds)];
-
-    // Invoke the instruction with the signer.
-    close_account_instruction.invoke_signed(&signer)?;
+    // Invoke the instruction, signing with the authority PDA.
+    close_account_instruction.invoke_signed(&pda_signer!(b"authority_account", &bump))?;
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,278 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke,
+    entrypoint,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::common::error::ExampleError;
+use crate::common::token_state::{Mint, MINT_LEN, TOKEN_ACCOUNT_LEN};
+
+/// The Token-2022 program, the only token program able to close mints.
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    five8_const::decode_32_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// The Token-2022 `AccountType` of a mint, stored right after the base account length.
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// The Token-2022 extension type of `MintCloseAuthority`.
+const MINT_CLOSE_AUTHORITY_EXTENSION: u16 = 3;
+
+/// The discriminator of the token `CloseAccount` instruction.
+const CLOSE_ACCOUNT_DISCRIMINATOR: u8 = 9;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the close mint instruction.
+    process_close_mint(accounts)
+}
+
+/// Ensures a Token-2022 mint can be closed, returning its close authority.
+///
+/// An extended mint stores the 82-byte base mint, padding up to the size of a token account,
+/// its account type and then the extensions as `[type: u16][length: u16][value]` entries.
+/// A mint can only be closed once its supply is zero and the `MintCloseAuthority` extension
+/// holds a non-zero key.
+///
+/// ### Parameters:
+/// - `data`: The raw mint data.
+///
+/// ### Returns:
+/// - `Result<Pubkey, ProgramError>`: The close authority, `InvalidAccountData` if the data is
+///   not an extended mint, `UninitializedAccount` if the mint is not initialized,
+///   `SupplyNotZero` if tokens are still in circulation, or `NoCloseAuthority` if the
+///   extension is missing or unset.
+pub fn require_closable_mint(data: &[u8]) -> Result<Pubkey, ProgramError> {
+    // Ensure the data holds an extended mint.
+    if data.get(TOKEN_ACCOUNT_LEN) != Some(&ACCOUNT_TYPE_MINT) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The base mint comes first, with the same layout as an original token mint.
+    let mint = Mint::from_bytes(&data[..MINT_LEN])?;
+
+    // Ensure the mint is initialized.
+    if !mint.is_initialized() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    // Ensure no tokens are left in circulation.
+    if mint.supply() != 0 {
+        return Err(ExampleError::SupplyNotZero.into());
+    }
+
+    // Walk the extensions looking for the close authority.
+    let mut extensions = &data[TOKEN_ACCOUNT_LEN + 1..];
+    while let [t0, t1, l0, l1, rest @ ..] = extensions {
+        let length = usize::from(u16::from_le_bytes([*l0, *l1]));
+        let value = rest.get(..length).ok_or(ProgramError::InvalidAccountData)?;
+
+        if u16::from_le_bytes([*t0, *t1]) == MINT_CLOSE_AUTHORITY_EXTENSION {
+            let authority: Pubkey = value
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+
+            // An all-zero key means the close authority was never set.
+            if authority == [0; 32] {
+                break;
+            }
+
+            return Ok(authority);
+        }
+
+        extensions = &rest[length..];
+    }
+
+    Err(ExampleError::NoCloseAuthority.into())
+}
+
+/// Processes the Token-2022 `CloseAccount` instruction on a mint.
+///
+/// Token-2022 lets the close authority of an empty mint reclaim its rent. The supply and the
+/// close authority are checked before the CPI so that a mint that can never be closed fails
+/// with a dedicated error. The token program still checks that the authority matches.
+/// `pinocchio_token` targets the original token program, so the instruction is built by hand.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint to close.
+/// 1. `[WRITE]` The account receiving the mint's lamports.
+/// 2. `[SIGNER]` The mint's close authority.
+/// 3. `[]` The Token-2022 program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_close_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account, destination_account, authority_account, token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the CPI goes to Token-2022.
+    if token_program.key() != &TOKEN_2022_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Ensure the mint is owned by Token-2022.
+    if !mint_account.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Ensure the mint and the destination are writable.
+    if !mint_account.is_writable() || !destination_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the close authority signed.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the mint is empty and has a close authority.
+    require_closable_mint(&mint_account.try_borrow_data()?)?;
+
+    // Close the mint.
+    invoke(
+        &Instruction {
+            program_id: token_program.key(),
+            accounts: &[
+                AccountMeta::writable(mint_account.key()),
+                AccountMeta::writable(destination_account.key()),
+                AccountMeta::readonly_signer(authority_account.key()),
+            ],
+            data: &[CLOSE_ACCOUNT_DISCRIMINATOR],
+        },
+        &[mint_account, destination_account, authority_account],
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{require_closable_mint, TOKEN_2022_PROGRAM_ID};
+    use crate::common::error::ExampleError;
+    use crate::common::token_state::{MINT_LEN, TOKEN_ACCOUNT_LEN};
+    use crate::test_utils::{assert_custom_error, metas, token_2022_mint_data};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, WritableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that an empty mint with a close authority can be closed.
+    #[test]
+    fn require_closable_mint_test() {
+        let authority = Pubkey::new_unique();
+
+        assert_eq!(
+            require_closable_mint(&token_2022_mint_data(0, authority)),
+            Ok(authority.to_bytes())
+        );
+    }
+
+    /// Tests that mints which can never be closed are rejected.
+    #[test]
+    fn require_closable_mint_rejects_test() {
+        // Tokens are still in circulation.
+        assert_eq!(
+            require_closable_mint(&token_2022_mint_data(1, Pubkey::new_unique())),
+            Err(ExampleError::SupplyNotZero.into())
+        );
+
+        // The close authority is unset.
+        assert_eq!(
+            require_closable_mint(&token_2022_mint_data(0, Pubkey::default())),
+            Err(ExampleError::NoCloseAuthority.into())
+        );
+
+        // The mint has no extensions at all.
+        assert_eq!(
+            require_closable_mint(
+                &token_2022_mint_data(0, Pubkey::new_unique())[..TOKEN_ACCOUNT_LEN + 1]
+            ),
+            Err(ExampleError::NoCloseAuthority.into())
+        );
+
+        // A legacy mint has no account type.
+        assert_eq!(
+            require_closable_mint(&token_2022_mint_data(0, Pubkey::new_unique())[..MINT_LEN]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    /// Tests that closing a mint with tokens in circulation is rejected before the CPI.
+    #[test]
+    fn close_mint_nonzero_supply_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        let token_program = Pubkey::new_from_array(TOKEN_2022_PROGRAM_ID);
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let destination = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let data = token_2022_mint_data(1_000, authority);
+        let mut mint_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(data.len()),
+            data.len(),
+            &token_program,
+        );
+        mint_account.set_data_from_slice(&data);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            metas![
+                mint => (mint, false, true),
+                destination => (destination, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account),
+                (
+                    destination,
+                    AccountSharedData::new(0, 0, &solana_sdk::system_program::ID),
+                ),
+                (
+                    authority,
+                    AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID),
+                ),
+                (
+                    token_program,
+                    AccountSharedData::new(0, 0, &solana_sdk::bpf_loader_upgradeable::ID),
+                ),
+            ],
+        );
+
+        assert_custom_error(&result, ExampleError::SupplyNotZero as u32);
+    }
+}
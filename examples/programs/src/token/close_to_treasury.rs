@@ -0,0 +1,175 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::CloseAccount;
+
+use crate::pda_signer;
+
+/// The only account allowed to receive the rent of closed token accounts.
+pub const TREASURY: Pubkey = [0x03; 32];
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the bump.
+    if data.is_empty() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the bump seed from the data.
+    let bump: [u8; 1] = [data[0]];
+
+    // Process the close to treasury instruction.
+    process_close_to_treasury(accounts, bump)
+}
+
+/// Processes the `CloseAccount` instruction, sending the rent to the treasury.
+///
+/// The token program sends the lamports of a closed account to whichever destination it is
+/// given, so the destination is pinned to [`TREASURY`] before the CPI. Otherwise anyone able
+/// to trigger the close could collect the rent.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `bump`: The bump seed of the authority PDA.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The account to close.
+/// 1. `[WRITE]` The treasury.
+/// 2. `[]` The account's owner, a PDA derived from `[b"authority_account"]`.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_close_to_treasury(accounts: &[AccountInfo], bump: [u8; 1]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [close_account, destination_account, authority_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the rent goes to the treasury.
+    if destination_account.key() != &TREASURY {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Ensure the account to close and the treasury are writable.
+    if !close_account.is_writable() || !destination_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Close the account, signing with the authority PDA.
+    CloseAccount {
+        account: close_account,
+        destination: destination_account,
+        authority: authority_account,
+    }
+    .invoke_signed(&pda_signer!(b"authority_account", &bump))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TREASURY;
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a close of an empty token account sending its rent to `destination`.
+    fn close(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        destination: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, u64) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let (authority, bump) = Pubkey::find_program_address(&[b"authority_account"], &program_id);
+        let account = Pubkey::new_unique();
+        let rent = mollusk.sysvars.rent.minimum_balance(spl_token::state::Account::LEN);
+
+        let token_account =
+            token_account(mollusk, Pubkey::new_from_array([0x02; 32]), authority, 0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[bump],
+            metas![
+                account => (account, false, true),
+                destination => (destination, false, true),
+                authority => (authority, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (account, token_account),
+            (destination, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (authority, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, account, rent)
+    }
+
+    /// Tests that the rent of the closed account is sent to the treasury.
+    #[test]
+    fn close_to_treasury_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let treasury = Pubkey::new_from_array(TREASURY);
+        let (instruction, accounts, account, rent) = close(&mut mollusk, program_id, treasury);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        assert_eq!(result.get_account(&account).unwrap().lamports(), 0);
+        assert_eq!(
+            result.get_account(&treasury).unwrap().lamports(),
+            1_000_000_000 + rent
+        );
+    }
+
+    /// Tests that any destination other than the treasury is rejected.
+    #[test]
+    fn close_to_treasury_wrong_destination_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = close(&mut mollusk, program_id, Pubkey::new_unique());
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
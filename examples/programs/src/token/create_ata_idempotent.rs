@@ -0,0 +1,293 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke,
+    entrypoint,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::common::token_state::{require_ata, ASSOCIATED_TOKEN_PROGRAM_ID};
+
+/// The discriminator of the Associated Token Account program's `CreateIdempotent`.
+const CREATE_IDEMPOTENT_DISCRIMINATOR: u8 = 1;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the idempotent create instruction.
+    process_create_ata_idempotent(accounts)
+}
+
+/// Processes the idempotent associated token account creation.
+///
+/// The account is created by the Associated Token Account program with its
+/// `CreateIdempotent` instruction, so it lives at the canonical address wallets and
+/// explorers look up. The ATA program creates and initializes the account when it is
+/// missing, and succeeds without charging the payer when it already exists for `wallet`
+/// and `mint`.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The payer funding the new account.
+/// 1. `[WRITE]` The associated token account.
+/// 2. `[]` The wallet that will own the associated token account.
+/// 3. `[]` The mint of the associated token account.
+/// 4. `[]` The system program.
+/// 5. `[]` The token program.
+/// 6. `[]` The Associated Token Account program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_create_ata_idempotent(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [payer_account, associated_account, wallet_account, mint_account, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the CPI goes to the Associated Token Account program.
+    if associated_token_program.key() != &ASSOCIATED_TOKEN_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Ensure the payer account is a signer.
+    if !payer_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the payer and the associated account are writable.
+    if !payer_account.is_writable() || !associated_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the associated account is the canonical one for the wallet and mint.
+    require_ata(associated_account.key(), wallet_account.key(), mint_account.key())?;
+
+    // Create the associated token account unless it already exists.
+    invoke(
+        &Instruction {
+            program_id: associated_token_program.key(),
+            accounts: &[
+                AccountMeta::writable_signer(payer_account.key()),
+                AccountMeta::writable(associated_account.key()),
+                AccountMeta::readonly(wallet_account.key()),
+                AccountMeta::readonly(mint_account.key()),
+                AccountMeta::readonly(system_program.key()),
+                AccountMeta::readonly(token_program.key()),
+            ],
+            data: &[CREATE_IDEMPOTENT_DISCRIMINATOR],
+        },
+        &[
+            payer_account,
+            associated_account,
+            wallet_account,
+            mint_account,
+            system_program,
+            token_program,
+        ],
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::token_state::ASSOCIATED_TOKEN_PROGRAM_ID;
+    use crate::test_utils::{metas, mint_account, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds the instruction for `associated`, or for the wallet's ATA if `None`, passing
+    /// `associated_token_program` as the Associated Token Account program.
+    ///
+    /// If `existing` is `Some(amount)`, the associated account already holds `amount` tokens.
+    /// The token and ATA programs are loaded so the CPI runs for real.
+    ///
+    /// Returns the instruction, the accounts, and the keys of the payer, the associated account
+    /// and the wallet.
+    fn setup(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        associated: Option<Pubkey>,
+        associated_token_program: Pubkey,
+        existing: Option<u64>,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+        let (ata_program, ata_program_account) = mollusk_token::associated_token::keyed_account();
+        mollusk_token::associated_token::add_program(mollusk);
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let payer = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_from_array([0x02; 32]);
+
+        // Derive the wallet's associated token account off-chain.
+        let (ata, _) = Pubkey::find_program_address(
+            &[wallet.as_ref(), spl_token::id().as_ref(), mint.as_ref()],
+            &Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM_ID),
+        );
+        let associated = associated.unwrap_or(ata);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            metas![
+                payer => (payer, true, true),
+                associated => (associated, false, true),
+                wallet => (wallet, false, false),
+                mint => (mint, false, false),
+                system_program => (system_program, false, false),
+                token_program => (token_program, false, false),
+                associated_token_program => (associated_token_program, false, false),
+            ],
+        );
+
+        let associated_account = match existing {
+            Some(amount) => token_account(mollusk, mint, wallet, amount),
+            None => AccountSharedData::new(0, 0, &solana_sdk::system_program::ID),
+        };
+        let associated_token_program_account = if associated_token_program == ata_program {
+            ata_program_account
+        } else {
+            AccountSharedData::default()
+        };
+
+        let accounts = vec![
+            (payer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (associated, associated_account),
+            (wallet, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+            (mint, mint_account(mollusk, Pubkey::new_unique(), 0)),
+            (system_program, system_program_account),
+            (token_program, token_program_account),
+            (associated_token_program, associated_token_program_account),
+        ];
+
+        (instruction, accounts, payer, associated, wallet)
+    }
+
+    /// Tests that a missing associated account is created for the wallet and paid by the payer.
+    #[test]
+    fn create_ata_idempotent_creates_when_missing_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, payer, ata, wallet) = setup(
+            &mut mollusk,
+            program_id,
+            None,
+            Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM_ID),
+            None,
+        );
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The account is now a token account of the wallet for the mint.
+        let account = result.get_account(&ata).unwrap();
+        assert_eq!(account.owner(), &spl_token::id());
+        let state = spl_token::state::Account::unpack(account.data()).unwrap();
+        assert_eq!(state.owner, wallet);
+        assert_eq!(state.mint, Pubkey::new_from_array([0x02; 32]));
+        assert_eq!(state.amount, 0);
+
+        // The payer funded the rent.
+        assert_eq!(
+            result.get_account(&payer).unwrap().lamports(),
+            1_000_000_000 - account.lamports()
+        );
+    }
+
+    /// Tests that an existing associated account is left untouched.
+    #[test]
+    fn create_ata_idempotent_skips_when_present_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, payer, ata, _) = setup(
+            &mut mollusk,
+            program_id,
+            None,
+            Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM_ID),
+            Some(42),
+        );
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The account keeps its balance and the payer pays nothing.
+        let (_, before) = accounts.iter().find(|(key, _)| *key == ata).unwrap();
+        let after = result.get_account(&ata).unwrap();
+        assert_eq!(after.data(), before.data());
+        assert_eq!(spl_token::state::Account::unpack(after.data()).unwrap().amount, 42);
+        assert_eq!(result.get_account(&payer).unwrap().lamports(), 1_000_000_000);
+    }
+
+    /// Tests that an address other than the canonical associated account is rejected.
+    #[test]
+    fn create_ata_idempotent_wrong_address_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = setup(
+            &mut mollusk,
+            program_id,
+            Some(Pubkey::new_unique()),
+            Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM_ID),
+            None,
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+
+    /// Tests that the CPI is not made to a program other than the ATA program.
+    #[test]
+    fn create_ata_idempotent_wrong_program_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) =
+            setup(&mut mollusk, program_id, None, Pubkey::new_unique(), None);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::IncorrectProgramId)],
+        );
+    }
+}
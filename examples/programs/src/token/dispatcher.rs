@@ -0,0 +1,350 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+#[cfg(feature = "error_return_data")]
+use pinocchio::cpi::set_return_data;
+
+#[cfg(feature = "error_return_data")]
+use crate::common::entry::error_code_bytes;
+use crate::common::ix::{expect_disc, parse_header, read_amount};
+
+use super::approve::process_approve;
+use super::burn::process_burn;
+use super::close_account::process_close_account;
+use super::mint_to::process_mint_to;
+use super::transfer_tokens::process_transfer;
+
+/// Discriminator of the `Transfer` instruction: `[amount: u64]`.
+pub const TRANSFER: u8 = 0;
+
+/// Discriminator of the `MintTo` instruction: `[amount: u64][bump: u8]`.
+pub const MINT_TO: u8 = 1;
+
+/// Discriminator of the `Burn` instruction: `[amount: u64][bump: u8]`.
+pub const BURN: u8 = 2;
+
+/// Discriminator of the `Approve` instruction: `[amount: u64][bump: u8]`.
+pub const APPROVE: u8 = 3;
+
+/// Discriminator of the `CloseAccount` instruction: `[bump: u8]`.
+pub const CLOSE_ACCOUNT: u8 = 4;
+
+/// The name of each instruction, keyed by its discriminator.
+pub const INSTRUCTIONS: &[(&str, u8)] = &[
+    ("transfer", TRANSFER),
+    ("mint_to", MINT_TO),
+    ("burn", BURN),
+    ("approve", APPROVE),
+    ("close_account", CLOSE_ACCOUNT),
+];
+
+/// Returns the name of the instruction with the given discriminator, if any.
+///
+/// ### Parameters:
+/// - `disc`: The instruction discriminator.
+///
+/// ### Returns:
+/// - `Option<&'static str>`: The instruction name, or `None` for unknown discriminators.
+pub fn name_for(disc: u8) -> Option<&'static str> {
+    INSTRUCTIONS
+        .iter()
+        .find(|(_, discriminator)| *discriminator == disc)
+        .map(|(name, _)| *name)
+}
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// The instruction data starts with a versioned header (see [`parse_header`]); the
+/// discriminator selects which token example handles the remaining payload, so a single
+/// program can expose all of them.
+///
+/// With the `error_return_data` feature, a failing handler's custom error code is also set
+/// as return data (4 bytes, little-endian) so clients can read it without parsing logs.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Split the header from the payload.
+    let (_version, discriminator, rest) = parse_header(data)?;
+
+    // Route the payload to the matching handler. Handlers taking the discriminator re-assert
+    // it; `parse_header` succeeded, so the data after the version byte is not empty.
+    let result = match discriminator {
+        TRANSFER => handle_transfer(accounts, &data[1..]),
+        MINT_TO => handle_mint_to(accounts, &data[1..]),
+        BURN => {
+            let (amount, bump) = read_amount_and_bump(rest)?;
+            process_burn(accounts, amount, bump)
+        }
+        APPROVE => {
+            let (amount, bump) = read_amount_and_bump(rest)?;
+            process_approve(accounts, amount, bump)
+        }
+        CLOSE_ACCOUNT => process_close_account(accounts, read_bump(rest)?),
+        _ => {
+            msg!(&format!(
+                "Unknown instruction discriminator {discriminator}, expected one of: {}",
+                known_instructions()
+            ));
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    };
+
+    // Name the failing instruction in the logs.
+    if let (Err(error), Some(name)) = (&result, name_for(discriminator)) {
+        msg!(&format!("Instruction {name} failed: {error:?}"));
+    }
+
+    // Expose the custom error code to clients as return data.
+    #[cfg(feature = "error_return_data")]
+    if let Some(code) = result.as_ref().err().and_then(error_code_bytes) {
+        set_return_data(&code);
+    }
+
+    result
+}
+
+/// Handles `[TRANSFER][amount: u64]`.
+fn handle_transfer(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let rest = expect_disc(data, TRANSFER)?;
+
+    process_transfer(accounts, read_amount(rest, 0)?, None)
+}
+
+/// Handles `[MINT_TO][amount: u64][bump: u8]`.
+fn handle_mint_to(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (amount, bump) = read_amount_and_bump(expect_disc(data, MINT_TO)?)?;
+
+    process_mint_to(accounts, amount, bump)
+}
+
+/// Lists the known instructions as `name=discriminator` pairs.
+fn known_instructions() -> String {
+    INSTRUCTIONS
+        .iter()
+        .map(|(name, discriminator)| format!("{name}={discriminator}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Reads the bump seed from the start of the payload.
+fn read_bump(data: &[u8]) -> Result<[u8; 1], ProgramError> {
+    // Ensure the data length is sufficient for the bump.
+    if data.is_empty() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok([data[0]])
+}
+
+/// Reads an amount followed by a bump seed from the payload.
+fn read_amount_and_bump(data: &[u8]) -> Result<(u64, [u8; 1]), ProgramError> {
+    let amount = read_amount(data, 0)?;
+    let bump = read_bump(&data[8..])?;
+
+    Ok((amount, bump))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        handle_mint_to, handle_transfer, known_instructions, name_for, INSTRUCTIONS, MINT_TO,
+        TRANSFER,
+    };
+    use crate::common::ix::{encode_amount, VERSION_1};
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer through the dispatcher with the given header version.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        version: u8,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        transfer_between_mints(mollusk, program_id, version, Pubkey::new_from_array([0x02; 32]))
+    }
+
+    /// Builds a transfer through the dispatcher whose recipient holds `recipient_mint`.
+    fn transfer_between_mints(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        version: u8,
+        recipient_mint: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let mut data = vec![version, TRANSFER];
+        data.extend_from_slice(&encode_amount(250));
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (sender_ta, token_account(mollusk, mint, authority, 1_000)),
+            (recipient_ta, token_account(mollusk, recipient_mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, recipient_ta)
+    }
+
+    /// Tests that every discriminator is unique and maps back to its name.
+    #[test]
+    fn discriminator_table_test() {
+        for (index, (name, discriminator)) in INSTRUCTIONS.iter().enumerate() {
+            assert!(
+                INSTRUCTIONS[index + 1..]
+                    .iter()
+                    .all(|(_, other)| other != discriminator),
+                "Duplicate discriminator {discriminator} for {name}",
+            );
+            assert_eq!(name_for(*discriminator), Some(*name));
+        }
+
+        assert_eq!(name_for(TRANSFER), Some("transfer"));
+        assert_eq!(name_for(u8::MAX), None);
+        assert_eq!(
+            known_instructions(),
+            "transfer=0, mint_to=1, burn=2, approve=3, close_account=4"
+        );
+    }
+
+    /// Tests that handlers reject a payload routed with another discriminator.
+    #[test]
+    fn handler_mismatched_discriminator_test() {
+        let mut data = vec![MINT_TO];
+        data.extend_from_slice(&encode_amount(1_000));
+        data.push(255);
+
+        // The discriminator is checked before any account is read.
+        assert_eq!(handle_transfer(&[], &data), Err(ProgramError::InvalidInstructionData));
+
+        data[0] = TRANSFER;
+        assert_eq!(handle_mint_to(&[], &data), Err(ProgramError::InvalidInstructionData));
+    }
+
+    /// Tests that a versioned transfer is routed to the transfer handler.
+    #[test]
+    fn dispatcher_transfer_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, recipient_ta) = transfer(&mut mollusk, program_id, VERSION_1);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let recipient = result.get_account(&recipient_ta).unwrap();
+        assert_eq!(spl_token::state::Account::unpack(recipient.data()).unwrap().amount, 250);
+    }
+
+    /// Tests that an unknown header version is rejected before any handler runs.
+    #[test]
+    fn dispatcher_unknown_version_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = transfer(&mut mollusk, program_id, VERSION_1 + 1);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidInstructionData)],
+        );
+    }
+
+    /// Tests that a truncated payload is rejected by the handler's parser.
+    #[test]
+    fn dispatcher_truncated_payload_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // `MintTo` expects an amount and a bump, only the amount is provided.
+        let mut data = vec![VERSION_1, MINT_TO];
+        data.extend_from_slice(&encode_amount(1));
+
+        mollusk.process_and_validate_instruction(
+            &Instruction::new_with_bytes(program_id, &data, vec![]),
+            &[],
+            &[Check::err(ProgramError::InvalidInstructionData)],
+        );
+    }
+
+    /// Tests that empty instruction data is rejected cleanly instead of panicking.
+    #[test]
+    fn dispatcher_empty_data_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        mollusk.process_and_validate_instruction(
+            &Instruction::new_with_bytes(program_id, &[], vec![]),
+            &[],
+            &[Check::err(ProgramError::InvalidInstructionData)],
+        );
+    }
+
+    /// Tests that a failing handler's custom error code is exposed as return data.
+    #[cfg(feature = "error_return_data")]
+    #[test]
+    fn dispatcher_error_return_data_test() {
+        use crate::common::error::ExampleError;
+        use crate::test_utils::assert_custom_error;
+
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        // Mismatched mints make the transfer handler fail with `MintMismatch`.
+        let (instruction, accounts, _) =
+            transfer_between_mints(&mut mollusk, program_id, VERSION_1, Pubkey::new_unique());
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::MintMismatch as u32);
+
+        assert_eq!(
+            result.return_data,
+            (ExampleError::MintMismatch as u32).to_le_bytes().to_vec()
+        );
+    }
+}
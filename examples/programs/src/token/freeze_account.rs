@@ -1,6 +1,7 @@
 use pinocchio::{
     account_info::AccountInfo,
     entrypoint,
+    msg,
     program_error::ProgramError,
     instruction::{Signer, Seed},
     pubkey::Pubkey,
@@ -9,6 +10,11 @@ use pinocchio::{
 
 use pinocchio_token::instructions::FreezeAccount;
 
+use crate::common::{
+    error::ExampleError,
+    token_state::{borrow_token_data, Mint},
+};
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -44,7 +50,8 @@ pub fn process_instruction(
 /// Processes the `FreezeAccount` instruction.
 ///
 /// This function handles the logic for freezing a token account. It validates the accounts
-/// and signers, constructs the instruction, and invokes it.
+/// and signers, constructs the instruction, and invokes it. Mints created without a freeze
+/// authority are rejected up front instead of letting the token program fail the CPI.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
@@ -77,6 +84,16 @@ pub fn process_freeze_account<'a>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Ensure the mint has a freeze authority at all.
+    {
+        let mint_data = borrow_token_data(mint_account)?;
+
+        if Mint::from_bytes(&mint_data)?.freeze_authority()?.is_none() {
+            msg!("Mint has no freeze authority");
+            return Err(ExampleError::NoFreezeAuthority.into());
+        }
+    }
+
     // Construct the `FreezeAccount` instruction.
     let freeze_account_instruction = FreezeAccount {
         account: account_to_freeze,
@@ -92,4 +109,57 @@ pub fn process_freeze_account<'a>(
     freeze_account_instruction.invoke_signed(&signer)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::ExampleError;
+    use crate::test_utils::{assert_custom_error, metas, mint_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::Instruction,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that freezing an account of a mint without a freeze authority fails early.
+    #[test]
+    fn freeze_account_no_freeze_authority_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let (freeze_authority, bump) =
+            Pubkey::find_program_address(&[b"freeze_authority"], &program_id);
+        let account = Pubkey::new_unique();
+
+        // Create a mint without a freeze authority.
+        let mint_account = mint_account(&mollusk, Pubkey::new_unique(), 0);
+
+        // Create the token account to freeze.
+        let token_account = token_account(&mollusk, mint, Pubkey::new_unique(), 0);
+
+        let mut data = vec![bump];
+        data.resize(8, 0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                account => (account, false, true),
+                mint => (mint, false, false),
+                freeze_authority => (freeze_authority, true, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (account, token_account),
+                (mint, mint_account),
+                (freeze_authority, AccountSharedData::default()),
+            ],
+        );
+        assert_custom_error(&result, ExampleError::NoFreezeAuthority as u32);
+    }
+}
@@ -0,0 +1,163 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::FreezeAccount;
+
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the batch freeze instruction.
+    process_freeze_batch(accounts)
+}
+
+/// Processes the batch freeze instruction.
+///
+/// This function freezes every token account passed after the fixed accounts, using a
+/// single freeze authority signature. All accounts must hold tokens of the given mint.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[]` The mint account.
+/// 1. `[SIGNER]` The mint's freeze authority.
+/// 2. `[]` The token program.
+/// 3. ..3+N. `[WRITE]` The token accounts to freeze.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_freeze_batch(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into the fixed accounts and the accounts to freeze.
+    let [mint_account, freeze_authority, _token_program, accounts_to_freeze @ ..] = accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure at least one account is being frozen.
+    if accounts_to_freeze.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Ensure the freeze authority is a signer.
+    if !freeze_authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate every account before freezing anything.
+    for account in accounts_to_freeze {
+        // Ensure the account is writable.
+        if !account.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Ensure the account holds tokens of this mint.
+        let account_data = borrow_token_data(account)?;
+        if TokenAccount::from_bytes(&account_data)?.mint() != mint_account.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Freeze each account in turn.
+    for account in accounts_to_freeze {
+        FreezeAccount {
+            account,
+            mint: mint_account,
+            freeze_authority,
+        }
+        .invoke()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{mint_state, packed_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+    use spl_token::state::AccountState;
+
+    /// Tests freezing three accounts of the same mint.
+    #[test]
+    fn freeze_batch_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_unique();
+        let freeze_authority = Pubkey::new_unique();
+        let targets = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let mut metas = vec![
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(freeze_authority, true),
+            AccountMeta::new_readonly(token_program, false),
+        ];
+        let mut accounts = vec![
+            (
+                mint,
+                packed_account(
+                    &mollusk,
+                    spl_token::state::Mint {
+                        freeze_authority: COption::Some(freeze_authority),
+                        ..mint_state(Pubkey::new_unique(), 0)
+                    },
+                ),
+            ),
+            (freeze_authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+        for target in targets {
+            metas.push(AccountMeta::new(target, false));
+            accounts.push((target, token_account(&mollusk, mint, Pubkey::new_unique(), 0)));
+        }
+
+        let instruction = Instruction::new_with_bytes(program_id, &[], metas);
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // Every account is now frozen.
+        for target in &targets {
+            let account =
+                spl_token::state::Account::unpack(result.get_account(target).unwrap().data())
+                    .unwrap();
+            assert_eq!(account.state, AccountState::Frozen);
+        }
+    }
+}
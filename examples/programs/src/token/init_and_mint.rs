@@ -0,0 +1,227 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_token::instructions::{InitializeAccount3, InitializeMint2, MintTo};
+
+use crate::common::token_state::{MINT_LEN, TOKEN_ACCOUNT_LEN};
+use crate::pda_signer;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the supply, the decimals and the bump.
+    if data.len() < 10 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the initial supply from the data.
+    let supply = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Extract the decimals of the new mint from the data.
+    let decimals = data[8];
+
+    // Extract the bump seed of the mint authority from the data.
+    let bump: [u8; 1] = [data[9]];
+
+    // Process the init and mint instruction.
+    process_init_and_mint(accounts, supply, decimals, bump)
+}
+
+/// Processes the init and mint instruction.
+///
+/// This function creates and initializes a mint whose authority is a PDA, creates and
+/// initializes the recipient's token account, and mints the initial supply to it, all in a
+/// single instruction. `InitializeMint2` and `InitializeAccount3` are used so no rent sysvar
+/// account is needed.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `supply`: The initial supply to mint.
+/// - `decimals`: The decimals of the new mint.
+/// - `bump`: The bump seed of the mint authority PDA.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The payer funding the new accounts.
+/// 1. `[WRITE, SIGNER]` The new mint account.
+/// 2. `[WRITE, SIGNER]` The new recipient token account.
+/// 3. `[]` The owner of the recipient token account.
+/// 4. `[]` The mint authority PDA derived from `[b"mint_authority"]`.
+/// 5. `[]` The system program.
+/// 6. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_init_and_mint(
+    accounts: &[AccountInfo],
+    supply: u64,   // The initial supply to mint.
+    decimals: u8,  // The decimals of the new mint.
+    bump: [u8; 1], // Bump seed of the mint authority PDA.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [payer_account, mint_account, token_account, owner_account, mint_authority, _system_program, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the payer and the new accounts are signers.
+    if !payer_account.is_signer() || !mint_account.is_signer() || !token_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let rent = Rent::get()?;
+
+    // Create the mint account, owned by the token program.
+    CreateAccount {
+        from: payer_account,
+        to: mint_account,
+        lamports: rent.minimum_balance(MINT_LEN),
+        space: MINT_LEN as u64,
+        owner: &pinocchio_token::ID,
+    }
+    .invoke()?;
+
+    // Initialize the mint with the PDA as its authority and no freeze authority.
+    InitializeMint2 {
+        mint: mint_account,
+        decimals,
+        mint_authority: mint_authority.key(),
+        freeze_authority: None,
+    }
+    .invoke()?;
+
+    // Create the recipient token account, owned by the token program.
+    CreateAccount {
+        from: payer_account,
+        to: token_account,
+        lamports: rent.minimum_balance(TOKEN_ACCOUNT_LEN),
+        space: TOKEN_ACCOUNT_LEN as u64,
+        owner: &pinocchio_token::ID,
+    }
+    .invoke()?;
+
+    // Initialize the recipient token account for the new mint.
+    InitializeAccount3 {
+        account: token_account,
+        mint: mint_account,
+        owner: owner_account.key(),
+    }
+    .invoke()?;
+
+    // Mint the initial supply, signing with the mint authority PDA.
+    MintTo {
+        mint: mint_account,
+        account: token_account,
+        mint_authority,
+        amount: supply,
+    }
+    .invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::metas;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests the full flow: the mint and the token account are created, and the recipient
+    /// holds the whole initial supply.
+    #[test]
+    fn init_and_mint_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the system and token programs and the Mollusk environment.
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+
+        let mut data = 1_000_000_u64.to_le_bytes().to_vec();
+        data.push(6);
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                payer => (payer, true, true),
+                mint => (mint, true, true),
+                token_account => (token_account, true, true),
+                owner => (owner, false, false),
+                mint_authority => (mint_authority, false, false),
+                system_program => (system_program, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (payer, AccountSharedData::new(10_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (mint, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (token_account, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (owner, AccountSharedData::default()),
+                (mint_authority, AccountSharedData::default()),
+                (system_program, system_program_account),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let mint_state =
+            spl_token::state::Mint::unpack(result.get_account(&mint).unwrap().data()).unwrap();
+        assert_eq!(mint_state.supply, 1_000_000);
+        assert_eq!(mint_state.decimals, 6);
+        assert_eq!(mint_state.mint_authority, COption::Some(mint_authority));
+
+        let account_state =
+            spl_token::state::Account::unpack(result.get_account(&token_account).unwrap().data())
+                .unwrap();
+        assert_eq!(account_state.mint, mint);
+        assert_eq!(account_state.owner, owner);
+        assert_eq!(account_state.amount, 1_000_000);
+    }
+}
@@ -2,13 +2,16 @@ use pinocchio::{
     account_info::AccountInfo,
     entrypoint,
     program_error::ProgramError,
-    instruction::{Signer, Seed},
     pubkey::Pubkey,
+    sysvars::rent::RENT_ID,
     ProgramResult,
 };
 
 use pinocchio_token::instructions::InitializeAccount;
 
+use crate::common::guards::require_key;
+use crate::common::token_state::{borrow_token_data, require_initialized_mint};
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -18,210 +21,199 @@ entrypoint!(process_instruction);
 /// Entry point for the program. This function is called when the program is invoked.
 ///
 /// ### Parameters:
-/// - `program_id`: The ID of the program being executed.
+/// - `_program_id`: The ID of the program being executed.
 /// - `accounts`: The accounts passed to the program.
-/// - `data`: Additional data passed to the program.
+/// - `_data`: Additional data passed to the program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the program execution.
 pub fn process_instruction(
-    program_id: &Pubkey,
+    _program_id: &Pubkey,
     accounts: &[AccountInfo],
-    data: &[u8],
+    _data: &[u8],
 ) -> ProgramResult {
-    // Ensure the data length is valid for the instruction.
-    if data.len() < 8 {
-        return Err(ProgramError::InvalidInstructionData);
-    }
-
-    // Extract the bump seed from the data.
-    let bump: [u8; 1] = unsafe { *(data.as_ptr().add(0) as *const [u8; 1]) };
-
     // Process the InitializeAccount instruction.
-    process_initialize_account(accounts, bump)
+    process_initialize_account(accounts)
 }
 
 /// Processes the `InitializeAccount` instruction.
 ///
-/// This function handles the logic for initializing a token account. It validates the accounts
-/// and signers, constructs the instruction, and invokes it.
+/// This function handles the logic for initializing a token account. It validates the accounts,
+/// constructs the instruction, and invokes it. No signature is required: the owner is read
+/// from the owner account's key. The mint must be an initialized mint, otherwise
+/// `UninitializedAccount` is returned before the CPI.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
-/// - `signers`: The signers array needed to authorize the transaction.
 ///
 /// ### Accounts:
 /// 0. `[WRITE]` The account to initialize.
 /// 1. `[]` The mint this account will be associated with.
 /// 2. `[]` The new account's owner/multisignature.
 /// 3. `[]` Rent sysvar.
+/// 4. `[]` The token program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
-pub fn process_initialize_account<'a>(
-    accounts: &'a [AccountInfo],
-    bump: [u8; 1], // The signers array needed to authorize the transaction.
-) -> ProgramResult {
+pub fn process_initialize_account(accounts: &[AccountInfo]) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [account_to_initialize, mint_account, owner_account, rent_sysvar] = accounts else {
+    let [account_to_initialize, mint_account, owner_account, rent_sysvar, _token_program] = accounts
+    else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     // Ensure that the account to initialize is writable.
-    assert!(account_to_initialize.is_writable());
+    if !account_to_initialize.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
 
     // Ensure the rent sysvar is valid by checking its key.
-    // assert_eq!(rent_sysvar.key(), &spl_token::solana_program::sysvar::rent::ID);
+    require_key(rent_sysvar, &RENT_ID)?;
+
+    // Ensure the mint is an initialized mint.
+    require_initialized_mint(&borrow_token_data(mint_account)?)?;
 
-    // Construct the `InitializeAccount` instruction.
-    let initialize_account_instruction = InitializeAccount {
+    // Construct and invoke the `InitializeAccount` instruction.
+    InitializeAccount {
         account: account_to_initialize,
         mint: mint_account,
         owner: owner_account,
         rent_sysvar,
-    };
-
-    let seeds = [Seed::from(b"freeze_authority"), Seed::from(&bump)];
-    let signer = [Signer::from(&seeds)];
-
-    // Invoke the instruction with the provided signers.
-    initialize_account_instruction.invoke_signed(&signer)?;
+    }
+    .invoke()?;
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_utils::{metas, mint_account};
     use mollusk_svm::{result::Check, Mollusk};
-    use pinocchio_token::state::TokenAccount;
     use solana_sdk::{
-        account::{AccountSharedData, ReadableAccount, WritableAccount},
-        instruction::{AccountMeta, Instruction},
-        program_option::COption,
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
         program_pack::Pack,
         pubkey::Pubkey,
-        sysvar::Sysvar,
     };
-    use spl_token::state::AccountState;
 
-    /// Tests the transfer functionality of the token program.
-    #[test]
-    fn transfer_test() {
-        // Define the program ID for the test.
-        let program_id = Pubkey::new_from_array([
-            0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-            0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-            0x01, 0x01, 0x01, 0x01,
-        ]);
-
-        // Initialize the Mollusk virtual machine and add the token program.
+    /// Builds the instruction, marking the target account writable or not.
+    fn initialize(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        writable: bool,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey, Pubkey) {
         let (token_program, token_program_account) = mollusk_token::token::keyed_account();
-        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
-        mollusk_token::token::add_program(&mut mollusk);
-
-        // Define the mint and accounts for the test.
-        let mint = Pubkey::new_from_array([
-            0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-            0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02,
-            0x02, 0x02, 0x02, 0x02,
-        ]);
-
-        let signer = Pubkey::new_unique();
-        let signer_account = AccountSharedData::new(
-            1_000_000_000 * 10,
-            spl_token::state::Account::LEN,
-            &program_id,
-        );
-        println!("signer_account balance: {:?}", signer_account.lamports());
+        let (rent_sysvar, rent_sysvar_account) = mollusk.sysvars.keyed_account_for_rent_sysvar();
+        mollusk_token::token::add_program(mollusk);
 
-        let recipient = Pubkey::new_unique();
-        let recipient_account = AccountSharedData::new(
-            1_000_000_000 * 10,
-            spl_token::state::Account::LEN,
-            &program_id,
-        );
-        println!(
-            "recipient_account balance: {:?}",
-            recipient_account.lamports()
-        );
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let owner = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
 
-        // Define token accounts for the signer and recipient.
-        let signer_ta = Pubkey::new_from_array([
-            0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03,
-            0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03,
-            0x03, 0x03, 0x03, 0x03,
-        ]);
-        let recipient_ta = Pubkey::new_from_array([
-            0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04,
-            0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04,
-            0x04, 0x04, 0x04, 0x04,
-        ]);
-
-        // Initialize the token accounts with balances.
-        let mut signer_ta_account =
-            AccountSharedData::new(0, spl_token::state::Account::LEN, &spl_token::id());
-        spl_token::state::Account {
-            mint,
-            owner: signer,
-            amount: 1_000_000,
-            delegate: COption::None,
-            state: AccountState::Initialized,
-            is_native: COption::None,
-            delegated_amount: 0,
-            close_authority: COption::None,
-        }
-        .pack_into_slice(signer_ta_account.data_as_mut_slice());
-
-        let mut recipient_ta_account =
-            AccountSharedData::new(0, spl_token::state::Account::LEN, &spl_token::id());
-        spl_token::state::Account {
-            mint,
-            owner: recipient,
-            amount: 1_000_000,
-            delegate: COption::None,
-            state: AccountState::Initialized,
-            is_native: COption::None,
-            delegated_amount: 0,
-            close_authority: COption::None,
-        }
-        .pack_into_slice(recipient_ta_account.data_as_mut_slice());
-
-        // Verify the ownership of the token accounts.
-        assert_eq!(signer_ta_account.owner(), &spl_token::id());
-        assert_eq!(recipient_ta_account.owner(), &spl_token::id());
-
-        // Define the transfer amount and construct the instruction data.
-        let amount = 1_000_u64;
-        let data = amount.to_le_bytes();
-
-        // Construct the transfer instruction.
         let instruction = Instruction::new_with_bytes(
             program_id,
-            &data,
-            vec![
-                AccountMeta::new(signer_ta, false),
-                AccountMeta::new(recipient_ta, false),
-                AccountMeta::new(signer, true),
-                AccountMeta::new_readonly(token_program, false),
+            &[],
+            metas![
+                account => (account, false, writable),
+                mint => (mint, false, false),
+                owner => (owner, false, false),
+                rent_sysvar => (rent_sysvar, false, false),
+                token_program => (token_program, false, false),
             ],
         );
 
-        // Process the instruction using the Mollusk virtual machine.
-        let result: mollusk_svm::result::InstructionResult = mollusk.process_instruction(
-            &instruction,
-            &vec![
-                (signer_ta, signer_ta_account.clone()),
-                (recipient_ta, recipient_ta_account.clone()),
-                (signer, signer_account.clone()),
-                (token_program, token_program_account.clone()),
-            ],
-        );
+        let accounts = vec![
+            (
+                account,
+                AccountSharedData::new(
+                    mollusk.sysvars.rent.minimum_balance(spl_token::state::Account::LEN),
+                    spl_token::state::Account::LEN,
+                    &spl_token::id(),
+                ),
+            ),
+            (mint, mint_account(mollusk, Pubkey::new_unique(), 0)),
+            (owner, AccountSharedData::default()),
+            (rent_sysvar, rent_sysvar_account),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, account, mint, owner)
+    }
+
+    /// Tests that the initialized account records the provided owner and mint.
+    #[test]
+    fn initialize_account_owner_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, account, mint, owner) = initialize(&mut mollusk, program_id, true);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
 
-        // Assert that the instruction was processed successfully.
         assert!(
             !result.program_result.is_err(),
             "Error while processing instruction",
         );
+
+        let state =
+            spl_token::state::Account::unpack(result.get_account(&account).unwrap().data()).unwrap();
+        assert_eq!(state.owner, owner);
+        assert_eq!(state.mint, mint);
+        assert_eq!(state.amount, 0);
+    }
+
+    /// Tests that a read-only target account is rejected.
+    #[test]
+    fn initialize_account_not_writable_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = initialize(&mut mollusk, program_id, false);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
     }
-}
\ No newline at end of file
+
+    /// Tests that an account passed in place of the rent sysvar is rejected.
+    #[test]
+    fn initialize_account_wrong_rent_sysvar_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (mut instruction, mut accounts, ..) = initialize(&mut mollusk, program_id, true);
+
+        // Swap the rent sysvar for an arbitrary account.
+        let spoofed = Pubkey::new_unique();
+        instruction.accounts[3].pubkey = spoofed;
+        accounts[3] = (spoofed, accounts[3].1.clone());
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+
+    /// Tests that an uninitialized mint is rejected with `UninitializedAccount`.
+    #[test]
+    fn initialize_account_uninitialized_mint_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, mut accounts, ..) = initialize(&mut mollusk, program_id, true);
+
+        // Replace the mint with an allocated but uninitialized one.
+        accounts[1].1 = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN,
+            &spl_token::id(),
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::UninitializedAccount)],
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::InitializeAccount3;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the owner.
+    if data.len() < 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the owner of the new token account from the data.
+    let owner = unsafe { *(data.as_ptr() as *const Pubkey) };
+
+    // Process the InitializeAccount3 instruction.
+    process_initialize_account3(accounts, &owner)
+}
+
+/// Processes the `InitializeAccount3` instruction.
+///
+/// This function initializes a token account. Unlike `InitializeAccount`, the owner is
+/// passed as instruction data and the rent sysvar account is not required.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `owner`: The owner of the new token account.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The account to initialize.
+/// 1. `[]` The mint this account will be associated with.
+/// 2. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_initialize_account3(
+    accounts: &[AccountInfo],
+    owner: &Pubkey, // The owner of the new token account.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [account_to_initialize, mint_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure that the account to initialize is writable.
+    if !account_to_initialize.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Construct and invoke the `InitializeAccount3` instruction.
+    InitializeAccount3 {
+        account: account_to_initialize,
+        mint: mint_account,
+        owner,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::mint_account;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+    use spl_token::state::AccountState;
+
+    /// Tests initializing a token account without passing the rent sysvar.
+    #[test]
+    fn initialize_account3_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Create an initialized mint.
+        let mint = Pubkey::new_unique();
+        let mint_account = mint_account(&mollusk, Pubkey::new_unique(), 0);
+
+        // Create the uninitialized token account, already allocated for the token program.
+        let token_account = Pubkey::new_unique();
+        let token_account_data = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(spl_token::state::Account::LEN),
+            spl_token::state::Account::LEN,
+            &spl_token::id(),
+        );
+
+        let owner = Pubkey::new_unique();
+
+        // Only three accounts: no rent sysvar.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            owner.as_ref(),
+            vec![
+                AccountMeta::new(token_account, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (token_account, token_account_data),
+                (mint, mint_account),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The account is initialized for the mint with the owner from the data.
+        let account =
+            spl_token::state::Account::unpack(result.get_account(&token_account).unwrap().data())
+                .unwrap();
+        assert_eq!(account.owner, owner);
+        assert_eq!(account.mint, mint);
+        assert_eq!(account.state, AccountState::Initialized);
+    }
+}
@@ -9,9 +9,17 @@ use pinocchio::{
 
 use pinocchio_token::instructions::InitializeMint;
 
+use crate::common::error::ExampleError;
+
 // A constant representing the program ID, decoded from a base58 string.
 const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
+/// The largest number of decimals accepted for a new mint.
+///
+/// UI amounts are `amount / 10^decimals`; beyond 9 decimals a `u64` supply no longer leaves
+/// room for a meaningful whole-token part, and clients converting to floats lose precision.
+pub const MAX_DECIMALS: u8 = 9;
+
 // Macro to define the program's entry point.
 entrypoint!(process_instruction);
 
@@ -38,10 +46,25 @@ pub fn process_instruction(
     process_initialize_mint(accounts, decimals, mint_authority, freeze_authority, signers)
 }
 
+/// Ensures `decimals` does not exceed [`MAX_DECIMALS`].
+///
+/// ### Parameters:
+/// - `decimals`: The decimals requested for the mint.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `ExampleError::InvalidDecimals` if the decimals are too high.
+pub fn require_valid_decimals(decimals: u8) -> Result<(), ProgramError> {
+    if decimals > MAX_DECIMALS {
+        return Err(ExampleError::InvalidDecimals.into());
+    }
+
+    Ok(())
+}
+
 /// Processes the `InitializeMint` instruction.
 ///
-/// This function handles the logic for initializing a mint account. It validates the accounts,
-/// constructs the instruction, and invokes it.
+/// This function handles the logic for initializing a mint account. It validates the accounts
+/// and the decimals, constructs the instruction, and invokes it.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
@@ -79,6 +102,9 @@ pub fn process_initialize_mint<'a>(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Ensure the decimals are within the supported range.
+    require_valid_decimals(decimals)?;
+
     // Construct the `InitializeMint` instruction.
     let initialize_mint_instruction = InitializeMint {
         mint: mint_account,
@@ -92,4 +118,28 @@ pub fn process_initialize_mint<'a>(
     initialize_mint_instruction.invoke_signed(signers)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{require_valid_decimals, MAX_DECIMALS};
+    use crate::common::error::ExampleError;
+    use pinocchio::program_error::ProgramError;
+
+    /// Tests that decimals from 0 up to the maximum are accepted.
+    #[test]
+    fn require_valid_decimals_in_range_test() {
+        assert_eq!(require_valid_decimals(0), Ok(()));
+        assert_eq!(require_valid_decimals(MAX_DECIMALS), Ok(()));
+        assert_eq!(MAX_DECIMALS, 9);
+    }
+
+    /// Tests that decimals above the maximum are rejected with `InvalidDecimals`.
+    #[test]
+    fn require_valid_decimals_too_high_test() {
+        assert_eq!(
+            require_valid_decimals(10),
+            Err(ProgramError::Custom(ExampleError::InvalidDecimals as u32))
+        );
+    }
+}
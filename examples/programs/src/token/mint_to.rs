@@ -1,7 +1,6 @@
 use pinocchio::{
     account_info::AccountInfo,
     entrypoint,
-    instruction::{Seed, Signer},
     program_error::ProgramError,
     pubkey::Pubkey,
     ProgramResult,
@@ -9,6 +8,13 @@ use pinocchio::{
 
 use pinocchio_token::instructions::MintTo;
 
+use crate::common::error::ExampleError;
+use crate::common::token_state::{borrow_token_data, Mint};
+use crate::pda_signer;
+
+#[cfg(feature = "reject_zero")]
+use crate::common::guards::require_nonzero;
+
 // Macro to define the program's entry point.
 entrypoint!(process_instruction);
 
@@ -55,6 +61,7 @@ pub fn process_instruction(
 /// 0. `[WRITE]` The mint account.
 /// 1. `[WRITE]` The account to mint tokens to.
 /// 2. `[SIGNER]` The mint's minting authority.
+/// 3. `[]` The token program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -64,11 +71,22 @@ pub fn process_mint_to(
     bump: [u8; 1], // Bump seed for the signer account.
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [mint_account, token_account, mint_authority, _token_program] = accounts else {
+    let [mint_account, token_account, mint_authority, token_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    // Ensure the token program account is the SPL Token program the CPI targets.
+    if token_program.key() != &pinocchio_token::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Ensure the mint is not also passed as the token account, which would make the token
+    // program borrow the same account twice and fail with an opaque error.
+    if mint_account.key() == token_account.key() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
     // Ensure the mint account is writable.
     assert!(mint_account.is_writable(), "Mint account is not writable");
 
@@ -78,6 +96,18 @@ pub fn process_mint_to(
     // Ensure the mint authority is a signer.
     assert!(mint_authority.is_signer(), "Mint authority is not a signer");
 
+    // Ensure the signer is the mint authority stored in the mint.
+    {
+        let mint_data = borrow_token_data(mint_account)?;
+        if Mint::from_bytes(&mint_data)?.mint_authority()?.as_ref() != Some(mint_authority.key()) {
+            return Err(ExampleError::MintAuthorityMismatch.into());
+        }
+    }
+
+    // Reject zero amounts, which are almost always a client bug.
+    #[cfg(feature = "reject_zero")]
+    require_nonzero(amount)?;
+
     // Construct the MintTo instruction.
     let mint_to_instruction = MintTo {
         mint: mint_account,
@@ -86,12 +116,352 @@ pub fn process_mint_to(
         amount,
     };
 
-    // Construct the signer seeds.
-    let seeds = [Seed::from(b"mint_authority"), Seed::from(&bump)];
-    let signer = [Signer::from(&seeds)];
-
-    // Invoke the instruction with the signer.
-    mint_to_instruction.invoke_signed(&signer)?;
+    // Invoke the instruction, signing with the mint authority PDA.
+    mint_to_instruction.invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::ExampleError;
+    use crate::test_utils::{assert_custom_error, metas, mint_account, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that minting zero tokens is rejected when `reject_zero` is enabled.
+    #[cfg(feature = "reject_zero")]
+    #[test]
+    fn mint_to_zero_amount_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Derive the mint authority PDA.
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+
+        let mint = Pubkey::new_unique();
+        let token_account_key = Pubkey::new_unique();
+
+        // Encode a zero amount followed by the bump.
+        let mut data = 0_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                mint => (mint, false, true),
+                destination => (token_account_key, false, true),
+                mint_authority => (mint_authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        // The guard fails before the CPI is attempted.
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account(&mollusk, mint_authority, 0)),
+                (token_account_key, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+        assert_custom_error(&result, ExampleError::ZeroAmount as u32);
+    }
+
+    /// Tests that accounts passed in the documented order are accepted.
+    ///
+    /// The order mirrors the `### Accounts` section of `process_mint_to`, so reordering
+    /// the destructure without updating the docs breaks this test.
+    #[test]
+    fn mint_to_documented_account_order_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let token_account_key = Pubkey::new_unique();
+
+        let mut data = 1_000_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        // 0. mint, 1. destination, 2. mint authority, 3. token program.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                mint => (mint, false, true),
+                destination => (token_account_key, false, true),
+                mint_authority => (mint_authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account(&mollusk, mint_authority, 0)),
+                (token_account_key, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let account = spl_token::state::Account::unpack(
+            result.get_account(&token_account_key).unwrap().data(),
+        )
+        .unwrap();
+        assert_eq!(account.amount, 1_000);
+    }
+
+    /// Tests that swapping the mint and the destination is rejected.
+    #[test]
+    fn mint_to_reordered_accounts_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let token_account_key = Pubkey::new_unique();
+
+        let mut data = 1_000_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        // The destination is passed first and the mint second.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                destination => (token_account_key, false, true),
+                mint => (mint, false, true),
+                mint_authority => (mint_authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (token_account_key, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (mint, mint_account(&mollusk, mint_authority, 0)),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            result.program_result.is_err(),
+            "Reordered accounts should fail",
+        );
+    }
+
+    /// Tests that a spoofed token program account is rejected.
+    #[test]
+    fn mint_to_spoofed_token_program_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let token_account_key = Pubkey::new_unique();
+
+        // An arbitrary account posing as the token program.
+        let spoofed_program = Pubkey::new_unique();
+
+        let mut data = 1_000_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                mint => (mint, false, true),
+                destination => (token_account_key, false, true),
+                mint_authority => (mint_authority, true, false),
+                token_program => (spoofed_program, false, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account(&mollusk, mint_authority, 0)),
+                (token_account_key, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (mint_authority, AccountSharedData::default()),
+                (spoofed_program, AccountSharedData::default()),
+            ],
+            &[Check::err(ProgramError::IncorrectProgramId)],
+        );
+    }
+
+    /// Tests that minting succeeds when the signer is the mint's stored authority.
+    #[test]
+    fn mint_to_correct_authority_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let token_account_key = Pubkey::new_unique();
+
+        let mut data = 500_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                mint => (mint, false, true),
+                destination => (token_account_key, false, true),
+                mint_authority => (mint_authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account(&mollusk, mint_authority, 100)),
+                (token_account_key, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let mint_state =
+            spl_token::state::Mint::unpack(result.get_account(&mint).unwrap().data()).unwrap();
+        assert_eq!(mint_state.supply, 600);
+    }
+
+    /// Tests that a signer other than the mint's stored authority is rejected.
+    #[test]
+    fn mint_to_wrong_authority_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let token_account_key = Pubkey::new_unique();
+
+        // The signer is not the authority stored in the mint.
+        let impostor = Pubkey::new_unique();
+
+        let mut data = 500_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                mint => (mint, false, true),
+                destination => (token_account_key, false, true),
+                mint_authority => (impostor, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account(&mollusk, mint_authority, 100)),
+                (token_account_key, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (impostor, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+        assert_custom_error(&result, ExampleError::MintAuthorityMismatch as u32);
+    }
+
+    /// Tests that passing the mint as the token account is rejected with `InvalidArgument`.
+    #[test]
+    fn mint_to_aliased_mint_and_token_account_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+
+        let mut data = 500_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        // The mint is passed in both the mint and the destination positions.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                mint => (mint, false, true),
+                destination => (mint, false, true),
+                mint_authority => (mint_authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account(&mollusk, mint_authority, 100)),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::MintTo;
+
+use crate::{
+    common::token_state::{borrow_token_data, TokenAccount},
+    pda_signer,
+};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the bump and the count.
+    if data.len() < 2 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the bump seed and the number of recipients from the data.
+    let bump: [u8; 1] = unsafe { *(data.as_ptr() as *const [u8; 1]) };
+    let count = unsafe { *(data.as_ptr().add(1) as *const u8) } as usize;
+
+    // Ensure the data holds exactly one amount per recipient.
+    let amounts = &data[2..];
+    if amounts.len() != count * 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Process the batch mint instruction.
+    process_mint_to_batch(accounts, amounts, bump)
+}
+
+/// Processes the batch mint instruction.
+///
+/// This function mints to every recipient token account in a single instruction, signing
+/// each `MintTo` CPI with the mint authority PDA. All recipients must hold the same mint.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `amounts`: The little-endian `u64` amounts, one per recipient, in account order.
+/// - `bump`: The bump seed for the mint authority PDA.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint account.
+/// 1. `[]` The mint authority PDA.
+/// 2. `[]` The token program.
+/// 3. ..3+N. `[WRITE]` The token accounts to mint to.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_mint_to_batch(
+    accounts: &[AccountInfo],
+    amounts: &[u8],  // Amounts to mint, one `u64` per recipient.
+    bump: [u8; 1],   // Bump seed for the mint authority PDA.
+) -> ProgramResult {
+    // Destructure the accounts array into the fixed accounts and the recipients.
+    let [mint_account, mint_authority, _token_program, recipients @ ..] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure there is exactly one amount per recipient.
+    if recipients.len() * 8 != amounts.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Ensure the mint account is writable.
+    if !mint_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Validate every recipient before minting anything.
+    for recipient in recipients {
+        // Ensure the recipient is writable.
+        if !recipient.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Ensure the recipient holds tokens of this mint.
+        let recipient_data = borrow_token_data(recipient)?;
+        if TokenAccount::from_bytes(&recipient_data)?.mint() != mint_account.key() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Mint to each recipient in turn.
+    for (recipient, amount) in recipients.iter().zip(amounts.chunks_exact(8)) {
+        let amount = u64::from_le_bytes(
+            amount
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        MintTo {
+            mint: mint_account,
+            account: recipient,
+            mint_authority,
+            amount,
+        }
+        .invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{mint_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests minting to three recipients in one instruction.
+    #[test]
+    fn mint_to_batch_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Derive the mint authority PDA and define the mint.
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let initial_supply = 1_000_u64;
+
+        // Define three recipients with distinct amounts.
+        let recipients = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let amounts = [100_u64, 200, 300];
+
+        // Encode `[bump][count][amounts...]`.
+        let mut data = vec![bump, recipients.len() as u8];
+        for amount in amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+
+        let mut metas = vec![
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(mint_authority, false),
+            AccountMeta::new_readonly(token_program, false),
+        ];
+        let mut accounts = vec![
+            (mint, mint_account(&mollusk, mint_authority, initial_supply)),
+            (mint_authority, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+        for recipient in recipients {
+            metas.push(AccountMeta::new(recipient, false));
+            accounts.push((recipient, token_account(&mollusk, mint, Pubkey::new_unique(), 0)));
+        }
+
+        let instruction = Instruction::new_with_bytes(program_id, &data, metas);
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // Each recipient received its amount.
+        for (recipient, amount) in recipients.iter().zip(amounts) {
+            let account =
+                spl_token::state::Account::unpack(result.get_account(recipient).unwrap().data())
+                    .unwrap();
+            assert_eq!(account.amount, amount);
+        }
+
+        // The supply increased by the total minted.
+        let mint_state =
+            spl_token::state::Mint::unpack(result.get_account(&mint).unwrap().data()).unwrap();
+        assert_eq!(mint_state.supply, initial_supply + amounts.iter().sum::<u64>());
+    }
+}
@@ -30,7 +30,7 @@ pub fn process_instruction(
     data: &[u8],
 ) -> ProgramResult {
     // Validate the length of the instruction data.
-    if data.len() < 9 {
+    if data.len() < 10 {
         return Err(ProgramError::InvalidInstructionData);
     }
 
@@ -58,6 +58,7 @@ pub fn process_instruction(
 /// 0. `[WRITE]` The mint account.
 /// 1. `[WRITE]` The account to mint tokens to.
 /// 2. `[SIGNER]` The mint's minting authority.
+/// 3. `[]` The token program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -68,7 +69,7 @@ pub fn process_mint_to_checked<'a>(
     bump: [u8; 1],          // Bump seed for the signer account.
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [mint_account, token_account, mint_authority] = accounts else {
+    let [mint_account, token_account, mint_authority, _token_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -105,4 +106,91 @@ pub fn process_mint_to_checked<'a>(
     mint_to_checked_instruction.invoke_signed(&signers)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::build_mint_to_checked_ix;
+    use crate::test_utils::{mint_state, packed_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// The decimals of the mint used by the tests.
+    const DECIMALS: u8 = 6;
+
+    /// Runs the instruction built by the client with the given decimals.
+    fn run_mint_to_checked(decimals: u8) -> (mollusk_svm::result::InstructionResult, Pubkey) {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Derive the mint authority PDA.
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let instruction = build_mint_to_checked_ix(
+            &program_id,
+            &mint,
+            &destination,
+            &mint_authority,
+            500,
+            decimals,
+            bump,
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (
+                    mint,
+                    packed_account(
+                        &mollusk,
+                        spl_token::state::Mint { decimals: DECIMALS, ..mint_state(mint_authority, 0) },
+                    ),
+                ),
+                (destination, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+
+        (result, destination)
+    }
+
+    /// Tests minting with the mint's exact decimals.
+    #[test]
+    fn mint_to_checked_matching_decimals_test() {
+        let (result, destination) = run_mint_to_checked(DECIMALS);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let account =
+            spl_token::state::Account::unpack(result.get_account(&destination).unwrap().data())
+                .unwrap();
+        assert_eq!(account.amount, 500);
+    }
+
+    /// Tests that mismatched decimals are rejected by the token program.
+    #[test]
+    fn mint_to_checked_mismatched_decimals_test() {
+        let (result, _) = run_mint_to_checked(DECIMALS + 1);
+
+        assert!(
+            result.program_result.is_err(),
+            "Mismatched decimals should fail",
+        );
+    }
+}
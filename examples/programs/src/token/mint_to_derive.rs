@@ -0,0 +1,207 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    log::sol_log_compute_units,
+    msg,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::MintTo;
+
+use crate::pda_signer;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// The bump is optional: when the data holds only the amount, the mint authority PDA is
+/// derived on-chain with `find_program_address`; when a trailing bump byte is present it
+/// is used as-is.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient to extract the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to mint from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Use the provided bump, or derive it on-chain when it is missing.
+    let bump = match data.get(8) {
+        Some(bump) => [*bump],
+        None => derive_mint_authority_bump(program_id, accounts)?,
+    };
+
+    // Process the MintTo instruction.
+    process_mint_to_derive(accounts, amount, bump)
+}
+
+/// Derives the mint authority PDA on-chain and returns its bump.
+///
+/// The compute units are logged before and after the derivation, so the cost of
+/// `find_program_address` shows up in the program logs.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program deriving the address.
+/// - `accounts`: The accounts passed to the program.
+///
+/// ### Returns:
+/// - `Result<[u8; 1], ProgramError>`: The canonical bump of the mint authority PDA.
+fn derive_mint_authority_bump(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> Result<[u8; 1], ProgramError> {
+    // The mint authority is the third account.
+    let mint_authority = accounts.get(2).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    msg!("Deriving mint authority");
+    sol_log_compute_units();
+
+    let (address, bump) = find_program_address(&[b"mint_authority"], program_id);
+
+    sol_log_compute_units();
+
+    // Ensure the passed authority is the derived PDA.
+    if mint_authority.key() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok([bump])
+}
+
+/// Processes the MintTo instruction.
+///
+/// This function validates the accounts and mints tokens, signing with the mint
+/// authority PDA.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `amount`: The amount of tokens to mint.
+/// - `bump`: The bump seed for the mint authority PDA.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint account.
+/// 1. `[WRITE]` The account to mint tokens to.
+/// 2. `[]` The mint authority PDA.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_mint_to_derive(
+    accounts: &[AccountInfo],
+    amount: u64,   // Amount of tokens to mint.
+    bump: [u8; 1], // Bump seed for the mint authority PDA.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account, token_account, mint_authority, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the mint and token accounts are writable.
+    if !mint_account.is_writable() || !token_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Construct the MintTo instruction and invoke it, signing with the PDA.
+    MintTo {
+        mint: mint_account,
+        account: token_account,
+        mint_authority,
+        amount,
+    }
+    .invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{mint_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    /// Runs the program with the given instruction data and returns the compute units used.
+    fn compute_units_for(data: impl Fn(u8) -> Vec<u8>) -> u64 {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let mint_account = mint_account(&mollusk, mint_authority, 0);
+
+        let destination_account = token_account(&mollusk, mint, Pubkey::new_unique(), 0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data(bump),
+            vec![
+                AccountMeta::new(mint, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(mint_authority, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account),
+                (destination, destination_account),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        result.compute_units_consumed
+    }
+
+    /// Tests that passing the bump is cheaper than deriving it on-chain.
+    #[test]
+    fn mint_to_derive_compute_units_test() {
+        // Only the amount: the bump is derived on-chain.
+        let derived = compute_units_for(|_| 1_000_u64.to_le_bytes().to_vec());
+
+        // The amount followed by the bump computed off-chain.
+        let provided = compute_units_for(|bump| {
+            let mut data = 1_000_u64.to_le_bytes().to_vec();
+            data.push(bump);
+            data
+        });
+
+        println!("derived bump: {derived} CUs, provided bump: {provided} CUs");
+        assert!(provided < derived);
+    }
+}
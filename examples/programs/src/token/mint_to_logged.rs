@@ -0,0 +1,231 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::MintTo;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to mint from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the logged MintTo instruction.
+    process_mint_to_logged(accounts, amount)
+}
+
+/// Formats the event line logged after a successful mint.
+///
+/// Keys are written as lowercase hex so the line can be built without a base58 encoder.
+///
+/// ### Parameters:
+/// - `mint`: The mint the tokens were minted from.
+/// - `destination`: The token account that received the tokens.
+/// - `amount`: The amount of tokens minted.
+///
+/// ### Returns:
+/// - `String`: The line written to the program logs.
+pub fn mint_event(mint: &Pubkey, destination: &Pubkey, amount: u64) -> String {
+    format!(
+        "MINT_EVENT mint={} dest={} amount={amount}",
+        hex(mint),
+        hex(destination)
+    )
+}
+
+/// Emits the event line of a successful mint.
+///
+/// The log sink is passed in so the emitted line can be captured, as `run_with_log` does
+/// in `common::entry`.
+///
+/// ### Parameters:
+/// - `mint`: The mint the tokens were minted from.
+/// - `destination`: The token account that received the tokens.
+/// - `amount`: The amount of tokens minted.
+/// - `log`: The sink receiving the event line.
+pub fn emit_mint_event(
+    mint: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+    mut log: impl FnMut(&str),
+) {
+    log(&mint_event(mint, destination, amount));
+}
+
+/// Encodes a key as lowercase hex.
+fn hex(key: &Pubkey) -> String {
+    key.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Processes the `MintTo` instruction and logs an event for indexers.
+///
+/// The event is only emitted once the CPI has succeeded, so indexers never see a mint that
+/// did not happen.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `amount`: The amount of tokens to mint.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint account.
+/// 1. `[WRITE]` The account to mint tokens to.
+/// 2. `[SIGNER]` The mint's minting authority.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_mint_to_logged(
+    accounts: &[AccountInfo],
+    amount: u64, // Amount of tokens to mint.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account, token_account, mint_authority, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the mint and token accounts are writable.
+    if !mint_account.is_writable() || !token_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the mint authority is a signer.
+    if !mint_authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Mint the tokens.
+    MintTo {
+        mint: mint_account,
+        account: token_account,
+        mint_authority,
+        amount,
+    }
+    .invoke()?;
+
+    // Emit the event for indexers.
+    emit_mint_event(mint_account.key(), token_account.key(), amount, |line| msg!(line));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{emit_mint_event, mint_event};
+    use crate::test_utils::{metas, mint_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests the event line format.
+    #[test]
+    fn mint_event_test() {
+        let line = mint_event(&[0xab; 32], &[0x01; 32], 42);
+
+        assert_eq!(
+            line,
+            format!("MINT_EVENT mint={} dest={} amount=42", "ab".repeat(32), "01".repeat(32))
+        );
+    }
+
+    /// Tests the exact line handed to the log sink, keys in byte order.
+    #[test]
+    fn emit_mint_event_test() {
+        let mut destination = [0; 32];
+        destination.iter_mut().enumerate().for_each(|(index, byte)| *byte = index as u8);
+
+        let mut logs = Vec::new();
+        emit_mint_event(&[0x02; 32], &destination, 750, |line| logs.push(line.to_string()));
+
+        assert_eq!(
+            logs,
+            vec![concat!(
+                "MINT_EVENT ",
+                "mint=0202020202020202020202020202020202020202020202020202020202020202 ",
+                "dest=000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f ",
+                "amount=750",
+            )
+            .to_string()]
+        );
+    }
+
+    /// Tests that the mint succeeds and credits the destination.
+    #[test]
+    fn mint_to_logged_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let mint_account = mint_account(&mollusk, authority, 0);
+
+        let destination_account = token_account(&mollusk, mint, Pubkey::new_unique(), 0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &750_u64.to_le_bytes(),
+            metas![
+                mint => (mint, false, true),
+                destination => (destination, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account),
+                (destination, destination_account),
+                (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let minted =
+            spl_token::state::Account::unpack(result.get_account(&destination).unwrap().data())
+                .unwrap()
+                .amount;
+        assert_eq!(minted, 750);
+    }
+}
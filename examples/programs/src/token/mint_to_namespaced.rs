@@ -0,0 +1,160 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::MintTo;
+
+use crate::pda_signer;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount and the bump.
+    if data.len() < 9 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to mint from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Extract the bump seed from the data.
+    let bump: [u8; 1] = unsafe { *(data.as_ptr().add(8) as *const [u8; 1]) };
+
+    // Process the namespaced MintTo instruction.
+    process_mint_to_namespaced(accounts, amount, bump)
+}
+
+/// Processes the `MintTo` instruction with a per-mint authority.
+///
+/// The mint authority is a PDA derived from `[b"mint", mint, bump]`, so each mint gets its
+/// own authority instead of sharing a single program-wide one. All three seeds are passed to
+/// the signer.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `amount`: The amount of tokens to mint.
+/// - `bump`: The bump seed of the mint authority PDA.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint account.
+/// 1. `[WRITE]` The account to mint tokens to.
+/// 2. `[]` The mint authority PDA derived from `[b"mint", mint]`.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_mint_to_namespaced(
+    accounts: &[AccountInfo],
+    amount: u64,   // Amount of tokens to mint.
+    bump: [u8; 1], // Bump seed of the mint authority PDA.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account, token_account, mint_authority, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the mint and token accounts are writable.
+    if !mint_account.is_writable() || !token_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Mint the tokens, signing with the namespaced mint authority PDA.
+    MintTo {
+        mint: mint_account,
+        account: token_account,
+        mint_authority,
+        amount,
+    }
+    .invoke_signed(&pda_signer!(b"mint", mint_account.key(), &bump))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, mint_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests minting with the authority PDA derived from `[b"mint", mint]`.
+    #[test]
+    fn mint_to_namespaced_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Derive the namespaced authority off-chain.
+        let mint = Pubkey::new_unique();
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint", mint.as_ref()], &program_id);
+
+        // Create a mint whose authority is the namespaced PDA.
+        let mint_account = mint_account(&mollusk, mint_authority, 0);
+
+        // Create the destination token account.
+        let destination = Pubkey::new_unique();
+        let destination_account = token_account(&mollusk, mint, Pubkey::new_unique(), 0);
+
+        let mut data = 1_000_u64.to_le_bytes().to_vec();
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                mint => (mint, false, true),
+                destination => (destination, false, true),
+                mint_authority => (mint_authority, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (mint, mint_account),
+                (destination, destination_account),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let account =
+            spl_token::state::Account::unpack(result.get_account(&destination).unwrap().data())
+                .unwrap();
+        assert_eq!(account.amount, 1_000);
+    }
+}
@@ -0,0 +1,500 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_system::instructions::CreateAccount;
+use pinocchio_token::instructions::MintTo;
+
+use crate::common::{
+    error::ExampleError,
+    guards::{require_key, require_owned_by_program},
+};
+use crate::pda_signer;
+use crate::state::tagged_account::{load_tagged, write_tag, DISCRIMINATOR_LEN};
+
+/// The number of slots in an epoch.
+pub const SLOTS_PER_EPOCH: u64 = 432_000;
+
+/// The maximum amount of tokens that can be minted per epoch.
+pub const EPOCH_MINT_CAP: u64 = 1_000;
+
+/// The discriminator tagging a rate limit state account.
+pub const RATE_LIMIT_STATE: [u8; DISCRIMINATOR_LEN] = *b"ratelim\0";
+
+/// The size of the rate limit state:
+/// `[tag: 8][mint: 32][minted: u64][last_epoch: u64]`, little-endian.
+pub const RATE_LIMIT_STATE_LEN: usize = DISCRIMINATOR_LEN + 32 + 8 + 8;
+
+/// Discriminator for the instruction creating the rate limit state of a mint.
+pub const INITIALIZE: u8 = 0;
+
+/// Discriminator for the rate-limited mint instruction.
+pub const MINT: u8 = 1;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Dispatch on the discriminator in the first byte of the data.
+    match data.split_first() {
+        Some((&INITIALIZE, _)) => process_initialize_rate_limit(program_id, accounts),
+        Some((&MINT, rest)) => {
+            // Ensure the data length is sufficient for the amount and the bump.
+            if rest.len() < 9 {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            // Extract the amount to mint from the data.
+            let amount = u64::from_le_bytes(rest[..8].try_into().unwrap());
+
+            // Extract the bump seed from the data.
+            let bump = [rest[8]];
+
+            // Process the rate-limited MintTo instruction.
+            process_mint_to_rate_limited(program_id, accounts, amount, bump)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// A read-only view over the rate limit state of a mint.
+pub struct RateLimitState<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> RateLimitState<'a> {
+    /// Creates a view over raw rate limit state data, validating its length.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if data.len() != RATE_LIMIT_STATE_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { data })
+    }
+
+    /// The mint the limit applies to.
+    pub fn mint(&self) -> &'a Pubkey {
+        self.data[8..40].try_into().unwrap()
+    }
+
+    /// The amount minted during the last epoch.
+    pub fn minted(&self) -> u64 {
+        u64::from_le_bytes(self.data[40..48].try_into().unwrap())
+    }
+
+    /// The epoch of the last mint.
+    pub fn last_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.data[48..56].try_into().unwrap())
+    }
+}
+
+/// Adds `amount` to the tokens minted during `current_epoch`.
+///
+/// The total restarts from zero when the epoch differs from the last recorded one.
+///
+/// ### Parameters:
+/// - `minted`: The amount minted during `last_epoch`.
+/// - `last_epoch`: The epoch of the last mint.
+/// - `current_epoch`: The current epoch.
+/// - `amount`: The amount to mint.
+///
+/// ### Returns:
+/// - `Result<u64, ProgramError>`: The new total for `current_epoch`, or
+///   `ExampleError::AmountExceedsCap` if it would exceed [`EPOCH_MINT_CAP`].
+pub fn minted_in_epoch(
+    minted: u64,
+    last_epoch: u64,
+    current_epoch: u64,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    // A new epoch starts from zero.
+    let minted = if current_epoch == last_epoch { minted } else { 0 };
+
+    match minted.checked_add(amount) {
+        Some(total) if total <= EPOCH_MINT_CAP => Ok(total),
+        _ => Err(ExampleError::AmountExceedsCap.into()),
+    }
+}
+
+/// Processes the instruction creating the rate limit state of a mint.
+///
+/// The state is the PDA derived from `[b"rate_limit", mint]`, so each mint has exactly one
+/// and a second, fresh allowance cannot be created for it. The state is tagged with
+/// [`RATE_LIMIT_STATE`] and records the mint it limits.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that owns the state account.
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[WRITE, SIGNER]` The payer funding the state account.
+/// 1. `[WRITE]` The rate limit state PDA derived from `[b"rate_limit", mint]`.
+/// 2. `[]` The mint account.
+/// 3. `[]` The system program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_initialize_rate_limit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [payer_account, state_account, mint_account, _system_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the payer account is a signer.
+    if !payer_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the state account is the PDA of the mint.
+    let (address, bump) = find_program_address(&[b"rate_limit", mint_account.key()], program_id);
+    if state_account.key() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Create the state account, signing for the PDA.
+    CreateAccount {
+        from: payer_account,
+        to: state_account,
+        lamports: Rent::get()?.minimum_balance(RATE_LIMIT_STATE_LEN),
+        space: RATE_LIMIT_STATE_LEN as u64,
+        owner: program_id,
+    }
+    .invoke_signed(&pda_signer!(b"rate_limit", mint_account.key(), &[bump]))?;
+
+    // Tag the state and record the mint it limits.
+    let mut state = state_account.try_borrow_mut_data()?;
+    write_tag(&mut state, RATE_LIMIT_STATE)?;
+    state[8..40].copy_from_slice(mint_account.key());
+
+    Ok(())
+}
+
+/// Processes the `MintTo` instruction with a per-epoch cap.
+///
+/// The current epoch is derived from the `Clock` slot. The mint's rate limit state, tagged
+/// with [`RATE_LIMIT_STATE`], keeps the amount minted during the last epoch; once the cap is
+/// reached, further mints fail until the next epoch. A state recorded for another mint is
+/// rejected, so it cannot lend its allowance.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the state account.
+/// - `accounts`: The accounts required for the instruction.
+/// - `amount`: The amount of tokens to mint.
+/// - `bump`: The bump seed of the mint authority PDA.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The rate limit state of the mint, owned by this program.
+/// 1. `[WRITE]` The mint account.
+/// 2. `[WRITE]` The account to mint tokens to.
+/// 3. `[]` The mint authority PDA derived from `[b"mint_authority"]`.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_mint_to_rate_limited(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,   // Amount of tokens to mint.
+    bump: [u8; 1], // Bump seed of the mint authority PDA.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [state_account, mint_account, token_account, mint_authority, _token_program] = accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the state account is a rate limit state owned by this program and writable.
+    require_owned_by_program(state_account, program_id)?;
+    load_tagged(state_account, RATE_LIMIT_STATE)?;
+    if !state_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the mint and token accounts are writable.
+    if !mint_account.is_writable() || !token_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Record the mint against the current epoch's cap.
+    {
+        let mut data = state_account.try_borrow_mut_data()?;
+        let state = RateLimitState::from_bytes(&data)?;
+
+        // Ensure the state limits this mint.
+        require_key(mint_account, state.mint())?;
+
+        let current_epoch = Clock::get()?.slot / SLOTS_PER_EPOCH;
+        let minted = minted_in_epoch(state.minted(), state.last_epoch(), current_epoch, amount)?;
+
+        data[40..48].copy_from_slice(&minted.to_le_bytes());
+        data[48..56].copy_from_slice(&current_epoch.to_le_bytes());
+    }
+
+    // Mint the tokens, signing with the mint authority PDA.
+    MintTo {
+        mint: mint_account,
+        account: token_account,
+        mint_authority,
+        amount,
+    }
+    .invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        minted_in_epoch, RateLimitState, EPOCH_MINT_CAP, INITIALIZE, MINT, RATE_LIMIT_STATE,
+        RATE_LIMIT_STATE_LEN, SLOTS_PER_EPOCH,
+    };
+    use crate::common::error::ExampleError;
+    use crate::state::tagged_account::DISCRIMINATOR_LEN;
+    use crate::test_utils::{assert_custom_error, metas, mint_account, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Encodes a rate limit state tagged with `tag` for `mint`, with nothing minted yet.
+    fn rate_limit_state(tag: [u8; DISCRIMINATOR_LEN], mint: &Pubkey) -> Vec<u8> {
+        [&tag[..], mint.as_ref(), &[0; 16]].concat()
+    }
+
+    /// Creates a rate limit state account owned by `program_id` holding `data`.
+    fn state_account(mollusk: &Mollusk, program_id: &Pubkey, data: &[u8]) -> AccountSharedData {
+        let mut account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(RATE_LIMIT_STATE_LEN),
+            RATE_LIMIT_STATE_LEN,
+            program_id,
+        );
+        account.set_data_from_slice(data);
+        account
+    }
+
+    /// Builds a mint of `amount` tokens limited by a state account holding `state`.
+    fn mint_to(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        amount: u64,
+        state: &[u8],
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let state_key = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let mut data = vec![MINT];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                state => (state_key, false, true),
+                mint => (mint, false, true),
+                destination => (destination, false, true),
+                mint_authority => (mint_authority, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (state_key, state_account(mollusk, &program_id, state)),
+            (mint, mint_account(mollusk, mint_authority, 0)),
+            (destination, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (mint_authority, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts)
+    }
+
+    /// Tests the per-epoch accounting.
+    #[test]
+    fn minted_in_epoch_test() {
+        // Mints accumulate within an epoch, up to the cap.
+        assert_eq!(minted_in_epoch(400, 3, 3, 600), Ok(EPOCH_MINT_CAP));
+        assert_eq!(
+            minted_in_epoch(400, 3, 3, 601),
+            Err(ProgramError::Custom(ExampleError::AmountExceedsCap as u32))
+        );
+
+        // A new epoch starts from zero.
+        assert_eq!(minted_in_epoch(EPOCH_MINT_CAP, 3, 4, 250), Ok(250));
+
+        // Overflow is reported as exceeding the cap instead of wrapping.
+        assert!(minted_in_epoch(1, 3, 3, u64::MAX).is_err());
+    }
+
+    /// Tests that initializing creates the mint's tagged state PDA.
+    #[test]
+    fn initialize_rate_limit_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (system_program, system_program_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let (state, _) = Pubkey::find_program_address(&[b"rate_limit", mint.as_ref()], &program_id);
+
+        let initialize = |state: Pubkey| {
+            Instruction::new_with_bytes(
+                program_id,
+                &[INITIALIZE],
+                metas![
+                    payer => (payer, true, true),
+                    state => (state, false, true),
+                    mint => (mint, false, false),
+                    system_program => (system_program, false, false),
+                ],
+            )
+        };
+        let accounts = |state: Pubkey| {
+            vec![
+                (payer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (state, AccountSharedData::default()),
+                (mint, mint_account(&mollusk, Pubkey::new_unique(), 0)),
+                (system_program, system_program_account.clone()),
+            ]
+        };
+
+        let result = mollusk.process_instruction(&initialize(state), &accounts(state));
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let state_account = result.get_account(&state).unwrap();
+        assert_eq!(state_account.owner(), &program_id);
+        assert_eq!(
+            state_account.data(),
+            rate_limit_state(RATE_LIMIT_STATE, &mint).as_slice()
+        );
+
+        // Any account other than the mint's PDA is rejected.
+        let other = Pubkey::new_unique();
+        mollusk.process_and_validate_instruction(
+            &initialize(other),
+            &accounts(other),
+            &[Check::err(ProgramError::InvalidSeeds)],
+        );
+    }
+
+    /// Tests that the cap blocks mints within an epoch and resets in the next one.
+    #[test]
+    fn mint_to_rate_limited_epoch_boundary_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let state = rate_limit_state(RATE_LIMIT_STATE, &mint);
+        let (mut instruction, mut accounts) =
+            mint_to(&mut mollusk, program_id, EPOCH_MINT_CAP, &state);
+        let (state, destination) = (instruction.accounts[0].pubkey, instruction.accounts[2].pubkey);
+
+        let balance = |accounts: &[(Pubkey, AccountSharedData)]| {
+            let (_, account) = accounts.iter().find(|(key, _)| *key == destination).unwrap();
+            spl_token::state::Account::unpack(account.data()).unwrap().amount
+        };
+        let set_amount = |instruction: &mut Instruction, amount: u64| {
+            instruction.data[1..9].copy_from_slice(&amount.to_le_bytes());
+        };
+
+        // Use up the cap during epoch 5.
+        mollusk.sysvars.clock.slot = 5 * SLOTS_PER_EPOCH + 10;
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        accounts = result.resulting_accounts;
+        assert_eq!(balance(&accounts), EPOCH_MINT_CAP);
+
+        // Any further mint in the same epoch is rejected.
+        mollusk.sysvars.clock.slot = 6 * SLOTS_PER_EPOCH - 1;
+        set_amount(&mut instruction, 1);
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::AmountExceedsCap as u32);
+
+        // The next epoch starts a fresh allowance.
+        mollusk.sysvars.clock.slot = 6 * SLOTS_PER_EPOCH;
+        set_amount(&mut instruction, 250);
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        accounts = result.resulting_accounts;
+        assert_eq!(balance(&accounts), EPOCH_MINT_CAP + 250);
+
+        // The state records the amount minted in epoch 6 for the same mint.
+        let (_, state_account) = accounts.iter().find(|(key, _)| *key == state).unwrap();
+        let state = RateLimitState::from_bytes(state_account.data()).unwrap();
+        assert_eq!(state.mint(), mint.as_ref());
+        assert_eq!(state.minted(), 250);
+        assert_eq!(state.last_epoch(), 6);
+    }
+
+    /// Tests that the state of another mint cannot lend its allowance.
+    #[test]
+    fn mint_to_rate_limited_other_mint_state_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let state = rate_limit_state(RATE_LIMIT_STATE, &Pubkey::new_unique());
+        let (instruction, accounts) = mint_to(&mut mollusk, program_id, 1, &state);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+
+    /// Tests that a program account without the rate limit tag cannot stand in for the state.
+    #[test]
+    fn mint_to_rate_limited_untagged_state_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let state = rate_limit_state([0; DISCRIMINATOR_LEN], &Pubkey::new_from_array([0x02; 32]));
+        let (instruction, accounts) = mint_to(&mut mollusk, program_id, 1, &state);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
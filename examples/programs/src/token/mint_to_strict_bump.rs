@@ -0,0 +1,185 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::MintTo;
+
+use crate::pda_signer;
+
+/// The lowest bump accepted by this example.
+///
+/// Canonical bumps are found by counting down from 255, so almost every PDA has a bump
+/// near the top of the range. A small value usually means the client passed an index or
+/// some other byte instead of the bump.
+pub const MIN_BUMP: u8 = 200;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient to extract the required fields.
+    if data.len() < 9 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to mint from the data.
+    let amount = unsafe { *(data.as_ptr().add(0) as *const u64) };
+
+    // Extract the bump seed from the data.
+    let bump: [u8; 1] = unsafe { *(data.as_ptr().add(8) as *const [u8; 1]) };
+
+    // Reject bumps below the floor before doing anything else.
+    require_bump_floor(bump, MIN_BUMP)?;
+
+    // Process the MintTo instruction.
+    process_mint_to_strict_bump(accounts, amount, bump)
+}
+
+/// Ensures a bump is not below the given floor.
+///
+/// ### Parameters:
+/// - `bump`: The bump passed by the client.
+/// - `floor`: The lowest accepted bump.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidSeeds` if the bump is below the floor.
+pub fn require_bump_floor(bump: [u8; 1], floor: u8) -> Result<(), ProgramError> {
+    if bump[0] < floor {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
+
+/// Processes the MintTo instruction.
+///
+/// This function validates the accounts and mints tokens, signing with the mint
+/// authority PDA.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `amount`: The amount of tokens to mint.
+/// - `bump`: The bump seed for the mint authority PDA.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint account.
+/// 1. `[WRITE]` The account to mint tokens to.
+/// 2. `[]` The mint authority PDA.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_mint_to_strict_bump(
+    accounts: &[AccountInfo],
+    amount: u64,   // Amount of tokens to mint.
+    bump: [u8; 1], // Bump seed for the mint authority PDA.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account, token_account, mint_authority, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the mint and token accounts are writable.
+    if !mint_account.is_writable() || !token_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Construct the MintTo instruction and invoke it, signing with the PDA.
+    MintTo {
+        mint: mint_account,
+        account: token_account,
+        mint_authority,
+        amount,
+    }
+    .invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{require_bump_floor, MIN_BUMP};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that canonical-looking bumps are accepted and low values rejected.
+    #[test]
+    fn require_bump_floor_test() {
+        assert!(require_bump_floor([255], MIN_BUMP).is_ok());
+        assert!(require_bump_floor([254], MIN_BUMP).is_ok());
+        assert!(require_bump_floor([MIN_BUMP], MIN_BUMP).is_ok());
+
+        assert_eq!(
+            require_bump_floor([MIN_BUMP - 1], MIN_BUMP),
+            Err(pinocchio::program_error::ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            require_bump_floor([0], MIN_BUMP),
+            Err(pinocchio::program_error::ProgramError::InvalidSeeds)
+        );
+    }
+
+    /// Tests that the program rejects a low bump before touching any account.
+    #[test]
+    fn mint_to_strict_bump_low_bump_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the Mollusk virtual machine for testing.
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+
+        let (mint_authority, _) = Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        // A client mistakenly passes an index (3) instead of the bump.
+        let mut data = 1_000_u64.to_le_bytes().to_vec();
+        data.push(3);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(mint, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(mint_authority, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (mint, AccountSharedData::default()),
+                (destination, AccountSharedData::default()),
+                (mint_authority, AccountSharedData::default()),
+                (token_program, token_program_account),
+            ],
+            &[Check::err(ProgramError::InvalidSeeds)],
+        );
+    }
+}
@@ -0,0 +1,612 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::MintTo;
+
+use crate::common::error::ExampleError;
+use crate::common::guards::{load_state, require_key, require_owned_by_program};
+use crate::pda_signer;
+use crate::state::tagged_account::{load_tagged, write_tag, DISCRIMINATOR_LEN};
+
+/// The discriminator tagging a raffle seed state account.
+pub const RAFFLE_STATE: [u8; DISCRIMINATOR_LEN] = *b"raffle\0\0";
+
+/// The largest number of candidates a raffle can hold.
+pub const MAX_CANDIDATES: usize = 8;
+
+/// The size of the seed state:
+/// `[tag: 8][seed: u64][authority: 32][count: u8][candidates: MAX_CANDIDATES * 32]`.
+pub const SEED_STATE_LEN: usize = DISCRIMINATOR_LEN + 8 + 32 + 1 + MAX_CANDIDATES * 32;
+
+/// Discriminator for the instruction recording the seed, the authority and the candidates.
+pub const INITIALIZE: u8 = 0;
+
+/// Discriminator for the instruction minting to a picked candidate.
+pub const MINT: u8 = 1;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Dispatch on the discriminator in the first byte of the data.
+    match data.split_first() {
+        Some((&INITIALIZE, rest)) => {
+            // Extract the seed from the data.
+            let seed = rest
+                .get(..8)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u64::from_le_bytes)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+
+            process_initialize_raffle(program_id, accounts, seed)
+        }
+        Some((&MINT, rest)) => {
+            // Ensure the data length is sufficient for the amount and the bump.
+            if rest.len() < 9 {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            // Extract the amount to mint from the data.
+            let amount = u64::from_le_bytes(rest[..8].try_into().unwrap());
+
+            // Extract the bump seed from the data.
+            let bump = [rest[8]];
+
+            process_mint_to_weighted(program_id, accounts, amount, bump)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// A read-only view over the seed state of a raffle.
+pub struct SeedState<'a> {
+    data: &'a [u8; SEED_STATE_LEN],
+}
+
+impl<'a> SeedState<'a> {
+    /// Creates a view over the seed state data.
+    pub fn new(data: &'a [u8; SEED_STATE_LEN]) -> Self {
+        Self { data }
+    }
+
+    /// The seed mixed with the slot to pick a recipient.
+    pub fn seed(&self) -> u64 {
+        u64::from_le_bytes(self.data[8..16].try_into().unwrap())
+    }
+
+    /// The authority allowed to run the raffle.
+    pub fn authority(&self) -> &'a Pubkey {
+        self.data[16..48].try_into().unwrap()
+    }
+
+    /// The stored candidates, in order, or `InvalidAccountData` if their count is out of
+    /// range.
+    pub fn candidates(&self) -> Result<impl ExactSizeIterator<Item = &'a [u8]>, ProgramError> {
+        let count = usize::from(self.data[48]);
+        if count == 0 || count > MAX_CANDIDATES {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(self.data[49..49 + count * 32].chunks_exact(32))
+    }
+}
+
+/// Picks one of `count` recipients from the slot and the stored seed.
+///
+/// The slot and the seed are mixed with the SplitMix64 finalizer so that consecutive slots
+/// land on unrelated recipients. The same inputs always pick the same recipient.
+///
+/// This is NOT cryptographic randomness: the slot is known in advance and the leader of a
+/// slot can choose whether to include the transaction, so anyone able to time a transaction
+/// can influence the pick. Use a verifiable randomness source for anything of value.
+///
+/// ### Parameters:
+/// - `slot`: The current slot.
+/// - `seed`: The seed stored in the program's state.
+/// - `count`: The number of recipients, which must not be zero.
+///
+/// ### Returns:
+/// - `usize`: The index of the chosen recipient, below `count`.
+pub fn pick_recipient(slot: u64, seed: u64, count: usize) -> usize {
+    let mut z = (slot ^ seed).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+
+    (z % count as u64) as usize
+}
+
+/// Ensures the candidate keys are exactly the candidates stored in the seed state, in order.
+///
+/// ### Parameters:
+/// - `state`: The seed state.
+/// - `keys`: The keys of the candidate accounts passed to the instruction.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidAccountData` if the stored count is out of range,
+///   `InvalidArgument` if a different number of candidates is passed, or
+///   `ExampleError::RecipientNotWhitelisted` if a candidate is not the stored one.
+pub fn check_candidates<'a>(
+    state: &SeedState,
+    keys: impl ExactSizeIterator<Item = &'a Pubkey>,
+) -> Result<(), ProgramError> {
+    let stored = state.candidates()?;
+
+    if keys.len() != stored.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if keys.zip(stored).any(|(key, stored)| key != stored) {
+        return Err(ExampleError::RecipientNotWhitelisted.into());
+    }
+
+    Ok(())
+}
+
+/// Processes the instruction recording the seed, the authority and the candidates of a raffle.
+///
+/// The seed state is tagged with [`RAFFLE_STATE`] and written once, so later raffles run
+/// against the candidates recorded here rather than accounts supplied by the caller.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the seed state account.
+/// - `accounts`: The accounts required for the instruction.
+/// - `seed`: The seed mixed with the slot to pick a recipient.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The seed state account, owned by this program and still zeroed.
+/// 1. `[SIGNER]` The raffle authority.
+/// 2. ..2+N. `[]` The candidate token accounts, at most [`MAX_CANDIDATES`].
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_initialize_raffle(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    seed: u64, // The seed mixed with the slot to pick a recipient.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [seed_account, authority_account, candidates @ ..] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the seed state account is owned by this program and writable.
+    require_owned_by_program(seed_account, program_id)?;
+    if !seed_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure there is at least one candidate and no more than the state can hold.
+    if candidates.is_empty() || candidates.len() > MAX_CANDIDATES {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut state = seed_account.try_borrow_mut_data()?;
+
+    if state.len() != SEED_STATE_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Tag the state, which fails if the raffle has been recorded already.
+    write_tag(&mut state, RAFFLE_STATE)?;
+
+    state[8..16].copy_from_slice(&seed.to_le_bytes());
+    state[16..48].copy_from_slice(authority_account.key());
+    state[48] = candidates.len() as u8;
+    for (slot, candidate) in state[49..].chunks_exact_mut(32).zip(candidates) {
+        slot.copy_from_slice(candidate.key());
+    }
+
+    Ok(())
+}
+
+/// Processes the `MintTo` instruction for a recipient picked from the current slot.
+///
+/// A demo raffle: the `Clock` slot and a seed kept in a tagged, program-owned state account pick
+/// one of the trailing recipient accounts with [`pick_recipient`], and the whole amount is
+/// minted to it. See [`pick_recipient`] for why the pick is predictable.
+///
+/// The state also records the raffle authority and the candidates. Only the authority can
+/// run the raffle, and the trailing accounts must be the stored candidates in order, so a
+/// caller cannot pad the list with accounts of their own.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the seed state account.
+/// - `accounts`: The accounts required for the instruction.
+/// - `amount`: The amount of tokens to mint.
+/// - `bump`: The bump seed of the mint authority PDA.
+///
+/// ### Accounts:
+/// 0. `[]` The seed state account, owned by this program and tagged with [`RAFFLE_STATE`].
+/// 1. `[WRITE]` The mint account.
+/// 2. `[]` The mint authority PDA derived from `[b"mint_authority"]`.
+/// 3. `[SIGNER]` The raffle authority recorded in the seed state.
+/// 4. `[]` The token program.
+/// 5. ..5+N. `[WRITE]` The candidate token accounts, in the stored order.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_mint_to_weighted(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,   // Amount of tokens to mint.
+    bump: [u8; 1], // Bump seed of the mint authority PDA.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [seed_account, mint_account, mint_authority, authority_account, _token_program, recipients @ ..] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Load the raffle state kept by this program.
+    let state = load_state::<SEED_STATE_LEN>(seed_account, program_id)?;
+    load_tagged(seed_account, RAFFLE_STATE)?;
+    let state = SeedState::new(state);
+
+    // Ensure the raffle authority recorded in the state signed.
+    require_key(authority_account, state.authority())?;
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the candidates are the stored ones.
+    check_candidates(&state, recipients.iter().map(|recipient| recipient.key()))?;
+
+    // Pick the recipient for the current slot.
+    let token_account =
+        &recipients[pick_recipient(Clock::get()?.slot, state.seed(), recipients.len())];
+
+    // Ensure the mint and the chosen token account are writable.
+    if !mint_account.is_writable() || !token_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Mint the tokens, signing with the mint authority PDA.
+    MintTo {
+        mint: mint_account,
+        account: token_account,
+        mint_authority,
+        amount,
+    }
+    .invoke_signed(&pda_signer!(b"mint_authority", &bump))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_candidates, pick_recipient, SeedState, INITIALIZE, MINT, RAFFLE_STATE,
+        SEED_STATE_LEN,
+    };
+    use crate::common::error::ExampleError;
+    use crate::state::tagged_account::DISCRIMINATOR_LEN;
+    use crate::test_utils::{assert_custom_error, metas, mint_account, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Encodes a seed state tagged with `tag` holding `seed`, `authority` and `candidates`.
+    fn seed_state<K: AsRef<[u8]>>(
+        tag: [u8; DISCRIMINATOR_LEN],
+        seed: u64,
+        authority: &K,
+        candidates: &[K],
+    ) -> [u8; SEED_STATE_LEN] {
+        let mut state = [0u8; SEED_STATE_LEN];
+        state[..8].copy_from_slice(&tag);
+        state[8..16].copy_from_slice(&seed.to_le_bytes());
+        state[16..48].copy_from_slice(authority.as_ref());
+        state[48] = candidates.len() as u8;
+        for (index, candidate) in candidates.iter().enumerate() {
+            state[49 + index * 32..81 + index * 32].copy_from_slice(candidate.as_ref());
+        }
+        state
+    }
+
+    /// Builds a raffle of 500 tokens among the stored `candidates`, passing `passed` as the
+    /// candidate accounts and `signer` as the raffle authority.
+    fn raffle(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        state: [u8; SEED_STATE_LEN],
+        passed: &[Pubkey],
+        signer: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let (mint_authority, bump) =
+            Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let seed_state_key = Pubkey::new_unique();
+
+        let mut seed_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(SEED_STATE_LEN),
+            SEED_STATE_LEN,
+            &program_id,
+        );
+        seed_account.set_data_from_slice(&state);
+
+        let mut data = vec![MINT];
+        data.extend_from_slice(&500_u64.to_le_bytes());
+        data.push(bump);
+
+        let mut metas = metas![
+            seed_state => (seed_state_key, false, false),
+            mint => (mint, false, true),
+            mint_authority => (mint_authority, false, false),
+            authority => (signer, true, false),
+            token_program => (token_program, false, false),
+        ];
+        metas.extend(passed.iter().map(|key| AccountMeta::new(*key, false)));
+        let instruction = Instruction::new_with_bytes(program_id, &data, metas);
+
+        let mut accounts = vec![
+            (seed_state_key, seed_account),
+            (mint, mint_account(mollusk, mint_authority, 0)),
+            (mint_authority, AccountSharedData::default()),
+            (signer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+        accounts.extend(
+            passed
+                .iter()
+                .map(|key| (*key, token_account(mollusk, mint, Pubkey::new_unique(), 0))),
+        );
+
+        (instruction, accounts)
+    }
+
+    /// Tests that the pick is deterministic, in range and spread across the recipients.
+    #[test]
+    fn pick_recipient_test() {
+        assert_eq!(pick_recipient(1_234, 42, 5), pick_recipient(1_234, 42, 5));
+        assert_eq!(pick_recipient(1_234, 42, 1), 0);
+
+        // Every recipient is picked over a range of slots.
+        let mut picked = [false; 4];
+        for slot in 0..100 {
+            picked[pick_recipient(slot, 42, 4)] = true;
+        }
+        assert_eq!(picked, [true; 4]);
+    }
+
+    /// Tests the fields read through the seed state view.
+    #[test]
+    fn seed_state_fields_test() {
+        let data = seed_state(RAFFLE_STATE, 42, &[9; 32], &[[1; 32], [2; 32]]);
+        let state = SeedState::new(&data);
+
+        assert_eq!(state.seed(), 42);
+        assert_eq!(state.authority(), &[9; 32]);
+        assert_eq!(
+            state.candidates().unwrap().collect::<Vec<_>>(),
+            [&[1; 32][..], &[2; 32][..]]
+        );
+
+        // A state without candidates.
+        let data = seed_state(RAFFLE_STATE, 42, &[9; 32], &[]);
+        assert!(SeedState::new(&data).candidates().is_err());
+    }
+
+    /// Tests that only the stored candidates, in the stored order, are accepted.
+    #[test]
+    fn check_candidates_test() {
+        let candidates = [[1; 32], [2; 32]];
+        let data = seed_state(RAFFLE_STATE, 42, &[9; 32], &candidates);
+        let state = SeedState::new(&data);
+
+        assert_eq!(check_candidates(&state, candidates.iter()), Ok(()));
+
+        // Another account in place of a candidate.
+        assert_eq!(
+            check_candidates(&state, [[1; 32], [3; 32]].iter()),
+            Err(ExampleError::RecipientNotWhitelisted.into())
+        );
+
+        // The candidates in another order.
+        assert_eq!(
+            check_candidates(&state, [[2; 32], [1; 32]].iter()),
+            Err(ExampleError::RecipientNotWhitelisted.into())
+        );
+
+        // An extra account appended to the list.
+        assert_eq!(
+            check_candidates(&state, [[1; 32], [2; 32], [3; 32]].iter()),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    /// Tests that initializing records the tag, the seed, the authority and the candidates,
+    /// and cannot be repeated.
+    #[test]
+    fn initialize_raffle_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let seed_state_key = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let candidates: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+        let mut data = vec![INITIALIZE];
+        data.extend_from_slice(&42_u64.to_le_bytes());
+
+        let mut metas = metas![
+            seed_state => (seed_state_key, false, true),
+            authority => (authority, true, false),
+        ];
+        metas.extend(candidates.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+        let instruction = Instruction::new_with_bytes(program_id, &data, metas);
+
+        let mut accounts = vec![
+            (
+                seed_state_key,
+                AccountSharedData::new(
+                    mollusk.sysvars.rent.minimum_balance(SEED_STATE_LEN),
+                    SEED_STATE_LEN,
+                    &program_id,
+                ),
+            ),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+        ];
+        accounts.extend(candidates.iter().map(|key| (*key, AccountSharedData::default())));
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let account = result.get_account(&seed_state_key).unwrap();
+        assert_eq!(
+            account.data(),
+            seed_state(RAFFLE_STATE, 42, &authority, &candidates).as_slice()
+        );
+
+        // The recorded raffle cannot be replaced.
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &result.resulting_accounts,
+            &[Check::err(ProgramError::AccountAlreadyInitialized)],
+        );
+    }
+
+    /// Tests that a fixed slot always mints to the same recipient.
+    #[test]
+    fn mint_to_weighted_fixed_slot_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let authority = Pubkey::new_unique();
+        let recipients: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let state = seed_state(RAFFLE_STATE, 42, &authority, &recipients);
+        let (instruction, accounts) =
+            raffle(&mut mollusk, program_id, state, &recipients, authority);
+        let slot = 1_234;
+
+        let expected = pick_recipient(slot, 42, recipients.len());
+
+        // Processing the same slot twice picks the same recipient each time.
+        mollusk.sysvars.clock.slot = slot;
+        for _ in 0..2 {
+            let result = mollusk.process_instruction(&instruction, &accounts);
+            assert!(
+                !result.program_result.is_err(),
+                "Error while processing instruction",
+            );
+
+            for (index, key) in recipients.iter().enumerate() {
+                let account = result.get_account(key).unwrap();
+                let amount = spl_token::state::Account::unpack(account.data()).unwrap().amount;
+                assert_eq!(amount, if index == expected { 500 } else { 0 });
+            }
+        }
+    }
+
+    /// Tests that candidates other than the stored ones are rejected.
+    #[test]
+    fn mint_to_weighted_unlisted_candidate_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let authority = Pubkey::new_unique();
+        let candidates: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        let state = seed_state(RAFFLE_STATE, 42, &authority, &candidates);
+        let passed = [candidates[0], Pubkey::new_unique()];
+        let (instruction, accounts) = raffle(&mut mollusk, program_id, state, &passed, authority);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::RecipientNotWhitelisted as u32);
+    }
+
+    /// Tests that a signer other than the recorded authority is rejected.
+    #[test]
+    fn mint_to_weighted_wrong_authority_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let candidates: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        let state = seed_state(RAFFLE_STATE, 42, &Pubkey::new_unique(), &candidates);
+        let (instruction, accounts) = raffle(
+            &mut mollusk,
+            program_id,
+            state,
+            &candidates,
+            Pubkey::new_unique(),
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+
+    /// Tests that a program account without the raffle tag cannot stand in for the seed state.
+    #[test]
+    fn mint_to_weighted_untagged_seed_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let authority = Pubkey::new_unique();
+        let candidates: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        let state = seed_state([0; DISCRIMINATOR_LEN], 42, &authority, &candidates);
+        let (instruction, accounts) =
+            raffle(&mut mollusk, program_id, state, &candidates, authority);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    /// Tests that a seed account owned by another program is rejected.
+    #[test]
+    fn mint_to_weighted_foreign_seed_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let authority = Pubkey::new_unique();
+        let candidates = [Pubkey::new_unique()];
+        let state = seed_state(RAFFLE_STATE, 42, &authority, &candidates);
+        let (instruction, mut accounts) =
+            raffle(&mut mollusk, program_id, state, &candidates, authority);
+
+        // The seed account is owned by some other program.
+        accounts[0].1.set_owner(Pubkey::new_unique());
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountOwner)],
+        );
+    }
+}
@@ -3,31 +3,130 @@ pub mod approve_checked;
 pub mod burn;
 pub mod burn_checked;
 pub mod close_account;
+pub mod close_mint;
+pub mod close_to_treasury;
+pub mod create_ata_idempotent;
+pub mod dispatcher;
 pub mod freeze_account;
+pub mod freeze_batch;
+pub mod init_and_mint;
 pub mod initialize_account;
+pub mod initialize_account3;
 pub mod initialize_mint;
 pub mod mint_to;
+pub mod mint_to_batch;
 pub mod mint_to_checked;
+pub mod mint_to_derive;
+pub mod mint_to_indexed;
+pub mod mint_to_logged;
+pub mod mint_to_namespaced;
+pub mod mint_to_rate_limited;
+pub mod mint_to_strict_bump;
+pub mod mint_to_weighted;
+pub mod read_cpi_return;
+pub mod read_external_mint;
 pub mod revoke;
 pub mod set_authority;
+pub mod streamed_transfer;
 pub mod sync_native;
 pub mod thaw_account;
+pub mod transfer_all;
+pub mod transfer_audited;
+pub mod transfer_capped;
+pub mod transfer_dedup;
+pub mod transfer_from_delegate;
+pub mod transfer_from_pda_vault;
+pub mod transfer_multisig;
+pub mod transfer_no_alloc;
+pub mod transfer_safe;
+pub mod transfer_sponsored;
+pub mod transfer_strict;
+pub mod transfer_to_ata;
 pub mod transfer_tokens;
 pub mod transfer_checked;
+pub mod transfer_tracked;
+pub mod transfer_whitelisted;
+pub mod transfer_with_fee;
+pub mod transfer_with_memo;
+pub mod transfer_with_receipt;
+pub mod transfer_with_reserve;
+pub mod validate_mint_consistency;
 
 // pub use approve::*;
 // pub use approve_checked::*;
 // pub use burn::*;
 // pub use burn_checked::*;
 // pub use close_account::*;
+// pub use close_mint::*;
+// pub use close_to_treasury::*;
+// pub use create_ata_idempotent::*;
+// pub use dispatcher::*;
 // pub use freeze_account::*;
+// pub use freeze_batch::*;
+// pub use init_and_mint::*;
 // pub use initialize_account::*;
+// pub use initialize_account3::*;
 // pub use initialize_mint::*;
 // pub use mint_to::*;
+// pub use mint_to_batch::*;
 // pub use mint_to_checked::*;
+// pub use mint_to_derive::*;
+// pub use mint_to_indexed::*;
+// pub use mint_to_logged::*;
+// pub use mint_to_namespaced::*;
+// pub use mint_to_rate_limited::*;
+// pub use mint_to_strict_bump::*;
+// pub use mint_to_weighted::*;
+// pub use read_cpi_return::*;
+// pub use read_external_mint::*;
 // pub use revoke::*;
 // pub use set_authority::*;
+// pub use streamed_transfer::*;
 // pub use sync_native::*;
 // pub use thaw_account::*;
+// pub use transfer_all::*;
+// pub use transfer_audited::*;
+// pub use transfer_capped::*;
+// pub use transfer_dedup::*;
+// pub use transfer_from_delegate::*;
+// pub use transfer_from_pda_vault::*;
+// pub use transfer_multisig::*;
+// pub use transfer_no_alloc::*;
+// pub use transfer_safe::*;
+// pub use transfer_sponsored::*;
+// pub use transfer_strict::*;
+// pub use transfer_to_ata::*;
 // pub use transfer_tokens::*;
-// pub use transfer_checked::*;
\ No newline at end of file
+// pub use transfer_checked::*;
+// pub use transfer_tracked::*;
+// pub use transfer_whitelisted::*;
+// pub use transfer_with_fee::*;
+// pub use transfer_with_memo::*;
+// pub use transfer_with_receipt::*;
+// pub use transfer_with_reserve::*;
+// pub use validate_mint_consistency::*;
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    /// Asserts that a `process_*` function rejects an empty account slice.
+    macro_rules! assert_not_enough_accounts {
+        ($($name:ident => $call:expr;)+) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!($call, Err(ProgramError::NotEnoughAccountKeys));
+                }
+            )+
+        };
+    }
+
+    assert_not_enough_accounts! {
+        transfer_not_enough_accounts_test => super::transfer_tokens::process_transfer(&[], 1, None);
+        mint_to_not_enough_accounts_test => super::mint_to::process_mint_to(&[], 1, [255]);
+        burn_not_enough_accounts_test => super::burn::process_burn(&[], 1, [255]);
+        close_account_not_enough_accounts_test => super::close_account::process_close_account(&[], [255]);
+        approve_not_enough_accounts_test => super::approve::process_approve(&[], 1, [255]);
+    }
+}
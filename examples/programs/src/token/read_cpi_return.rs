@@ -0,0 +1,134 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::{get_return_data, invoke, set_return_data},
+    entrypoint,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the read CPI return instruction.
+    process_read_cpi_return(accounts)
+}
+
+/// Converts the `[supply: u64][decimals: u8]` return data of `read_external_mint` into the
+/// supply in whole tokens.
+///
+/// ### Parameters:
+/// - `data`: The return data of the `read_external_mint` program.
+///
+/// ### Returns:
+/// - `Result<u64, ProgramError>`: The supply divided by `10^decimals`, rounded down, or
+///   `InvalidAccountData` if the return data is malformed.
+pub fn whole_supply(data: &[u8]) -> Result<u64, ProgramError> {
+    let [supply @ .., decimals] = data else {
+        return Err(ProgramError::InvalidAccountData);
+    };
+
+    let supply = u64::from_le_bytes(
+        supply
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    );
+
+    // A `u64` supply has no whole tokens once the divisor no longer fits.
+    Ok(10_u64
+        .checked_pow(u32::from(*decimals))
+        .map_or(0, |divisor| supply / divisor))
+}
+
+/// Processes the read CPI return instruction.
+///
+/// The mint is passed to the `read_external_mint` program through a CPI, whose return data
+/// is then read back with `get_return_data`. Return data is shared by the whole transaction,
+/// so its program id is checked to be the callee's before it is trusted. The decimals are
+/// then used to express the supply in whole tokens, which is returned as a little-endian
+/// `u64`.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[]` The mint account.
+/// 1. `[]` The `read_external_mint` program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_read_cpi_return(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account, reader_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ask the reader program for the mint's supply and decimals.
+    invoke(
+        &Instruction {
+            program_id: reader_program.key(),
+            accounts: &[AccountMeta::readonly(mint_account.key())],
+            data: &[],
+        },
+        &[mint_account],
+    )?;
+
+    // Read the return data, making sure the reader program set it.
+    let return_data = get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+    if return_data.program_id() != reader_program.key() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Return the supply in whole tokens.
+    set_return_data(&whole_supply(return_data.as_slice())?.to_le_bytes());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::whole_supply;
+    use pinocchio::program_error::ProgramError;
+
+    /// Encodes return data the way `read_external_mint` does.
+    fn return_data(supply: u64, decimals: u8) -> Vec<u8> {
+        let mut data = supply.to_le_bytes().to_vec();
+        data.push(decimals);
+        data
+    }
+
+    /// Tests the conversion of the supply into whole tokens.
+    #[test]
+    fn whole_supply_test() {
+        assert_eq!(whole_supply(&return_data(123_456_789, 0)), Ok(123_456_789));
+        assert_eq!(whole_supply(&return_data(123_456_789, 6)), Ok(123));
+        assert_eq!(whole_supply(&return_data(999, 3)), Ok(0));
+
+        // Decimals too large for a `u64` divisor leave no whole tokens.
+        assert_eq!(whole_supply(&return_data(u64::MAX, 20)), Ok(0));
+    }
+
+    /// Tests that return data of the wrong length is rejected.
+    #[test]
+    fn whole_supply_malformed_test() {
+        assert_eq!(whole_supply(&[]), Err(ProgramError::InvalidAccountData));
+        assert_eq!(whole_supply(&[0; 8]), Err(ProgramError::InvalidAccountData));
+        assert_eq!(whole_supply(&[0; 10]), Err(ProgramError::InvalidAccountData));
+    }
+}
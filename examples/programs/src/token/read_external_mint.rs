@@ -0,0 +1,159 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::common::token_state::{borrow_token_data, Mint};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the read external mint instruction.
+    process_read_external_mint(accounts)
+}
+
+/// Reads the supply and decimals out of raw mint data.
+///
+/// ### Parameters:
+/// - `data`: The raw mint data.
+///
+/// ### Returns:
+/// - `Result<(u64, u8), ProgramError>`: The supply and decimals, or `InvalidAccountData` if
+///   the data is not an initialized mint.
+pub fn read_mint(data: &[u8]) -> Result<(u64, u8), ProgramError> {
+    // Ensure the data is exactly one mint and the mint is initialized.
+    let mint = Mint::from_bytes(data)?;
+    if !mint.is_initialized() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok((mint.supply(), mint.decimals()))
+}
+
+/// Processes the read external mint instruction.
+///
+/// Any program can read any account, so the data of an account owned by another program is
+/// only trustworthy once its owner and shape are checked: the mint must be owned by the
+/// token program and have the size of a mint. The supply and decimals are then returned
+/// as `[supply: u64][decimals: u8]` return data.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[]` The mint account, owned by the token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_read_external_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Borrow the mint data, checking it is owned by the token program.
+    let (supply, decimals) = read_mint(&borrow_token_data(mint_account)?)?;
+
+    // Return `[supply][decimals]` to the caller.
+    let mut return_data = [0u8; 9];
+    return_data[..8].copy_from_slice(&supply.to_le_bytes());
+    return_data[8] = decimals;
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{mint_account, mint_state, packed_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, WritableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that the returned supply and decimals match the packed mint.
+    #[test]
+    fn read_external_mint_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let mint = Pubkey::new_from_array([0x02; 32]);
+
+        let result = mollusk.process_instruction(
+            &Instruction::new_with_bytes(program_id, &[], vec![AccountMeta::new_readonly(mint, false)]),
+            &[(
+                mint,
+                packed_account(
+                    &mollusk,
+                    spl_token::state::Mint { decimals: 9, ..mint_state(Pubkey::new_unique(), 123_456_789) },
+                ),
+            )],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let mut expected = 123_456_789_u64.to_le_bytes().to_vec();
+        expected.push(9);
+        assert_eq!(result.return_data, expected);
+    }
+
+    /// Tests that a mint-shaped account owned by another program is rejected.
+    #[test]
+    fn read_external_mint_wrong_owner_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let mint = Pubkey::new_from_array([0x02; 32]);
+
+        // A valid mint layout, but owned by this program instead of the token program.
+        let mut mint_account = mint_account(&mollusk, Pubkey::new_unique(), 1);
+        mint_account.set_owner(program_id);
+
+        mollusk.process_and_validate_instruction(
+            &Instruction::new_with_bytes(program_id, &[], vec![AccountMeta::new_readonly(mint, false)]),
+            &[(mint, mint_account)],
+            &[Check::err(ProgramError::InvalidAccountOwner)],
+        );
+    }
+
+    /// Tests that a token-program account that is not a mint is rejected.
+    #[test]
+    fn read_external_mint_wrong_length_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let account = Pubkey::new_unique();
+
+        mollusk.process_and_validate_instruction(
+            &Instruction::new_with_bytes(program_id, &[], vec![AccountMeta::new_readonly(account, false)]),
+            &[(
+                account,
+                AccountSharedData::new(1_000_000_000, spl_token::state::Account::LEN, &spl_token::id()),
+            )],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
@@ -9,6 +9,12 @@ use pinocchio::{
 
 use pinocchio_token::instructions::Revoke;
 
+#[cfg(feature = "strict_revoke")]
+use crate::common::{
+    error::ExampleError,
+    token_state::{borrow_token_data, TokenAccount},
+};
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -44,7 +50,8 @@ pub fn process_instruction(
 /// Processes the `Revoke` instruction.
 ///
 /// This function handles the logic for revoking a token. It validates the accounts
-/// and signers, constructs the instruction, and invokes it.
+/// and signers, constructs the instruction, and invokes it. With the `strict_revoke`
+/// feature, revoking an account without a delegate fails instead of being a no-op.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
@@ -53,6 +60,7 @@ pub fn process_instruction(
 /// ### Accounts:
 /// 0. `[WRITE]` The source account.
 /// 1. `[SIGNER]` The source account owner.
+/// 2. `[]` The token program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -61,7 +69,7 @@ pub fn process_revoke<'a>(
     bump: [u8; 1],
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [source_account, owner_account] = accounts else {
+    let [source_account, owner_account, _token_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -76,6 +84,15 @@ pub fn process_revoke<'a>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Reject revoking when there is no delegate, which usually hides a client bug.
+    #[cfg(feature = "strict_revoke")]
+    if TokenAccount::from_bytes(&borrow_token_data(source_account)?)?
+        .delegate()?
+        .is_none()
+    {
+        return Err(ExampleError::NoDelegate.into());
+    }
+
     // Construct the `Revoke` instruction.
     let revoke_instruction = Revoke {
         source: source_account,
@@ -90,4 +107,89 @@ pub fn process_revoke<'a>(
     revoke_instruction.invoke_signed(&signers)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::Instruction,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a revoke on a token account without a delegate.
+    fn revoke_without_delegate(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let (owner, bump) = Pubkey::find_program_address(&[b"owner_account"], &program_id);
+        let source = Pubkey::new_unique();
+
+        let source_account = token_account(mollusk, Pubkey::new_from_array([0x02; 32]), owner, 100);
+
+        let mut data = vec![bump];
+        data.resize(8, 0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                source => (source, false, true),
+                owner => (owner, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (source, source_account),
+            (owner, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, source)
+    }
+
+    /// Tests that, by default, revoking without a delegate is a successful no-op.
+    #[cfg(not(feature = "strict_revoke"))]
+    #[test]
+    fn revoke_without_delegate_noop_test() {
+        use solana_sdk::account::ReadableAccount;
+
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, source) = revoke_without_delegate(&mut mollusk, program_id);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let account =
+            spl_token::state::Account::unpack(result.get_account(&source).unwrap().data()).unwrap();
+        assert_eq!(account.delegate, COption::None);
+    }
+
+    /// Tests that, with `strict_revoke`, revoking without a delegate is rejected.
+    #[cfg(feature = "strict_revoke")]
+    #[test]
+    fn revoke_without_delegate_strict_test() {
+        use crate::common::error::ExampleError;
+        use crate::test_utils::assert_custom_error;
+
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = revoke_without_delegate(&mut mollusk, program_id);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::NoDelegate as u32);
+    }
+}
@@ -1,92 +1,202 @@
-// use pinocchio::{
-//     account_info::AccountInfo,
-//     entrypoint,
-//     instruction::Signer,
-//     program_error::ProgramError,
-//     pubkey::Pubkey,
-//     ProgramResult,
-// };
-
-// use pinocchio_token::instructions::{AuthorityType, SetAuthority};
-
-// // A constant representing the program ID, decoded from a base58 string.
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::{AuthorityType, SetAuthority};
+
+use crate::common::ix::read_pubkey;
+
+// A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
-// // Macro to define the program's entry point.
-// entrypoint!(process_instruction);
-
-// /// Entry point for the program. This function is called when the program is invoked.
-// ///
-// /// ### Parameters:
-// /// - `_program_id`: The ID of the program being executed.
-// /// - `accounts`: The accounts passed to the program.
-// /// - `data`: Additional data passed to the program.
-// ///
-// /// ### Returns:
-// /// - `ProgramResult`: Indicates success or failure of the program execution.
-// pub fn process_instruction(
-//     _program_id: &Pubkey,
-//     accounts: &[AccountInfo],
-//     data: &[u8],
-// ) -> ProgramResult {
-//     // Ensure the data length is sufficient for processing.
-//     if data.len() < 8 {
-//         return Err(ProgramError::InvalidInstructionData);
-//     }
-
-//     // Call the `process_set_authority` function to handle the instruction logic.
-//     process_set_authority(accounts, authority_type, new_authority, signers)
-// }
-
-// /// Processes the `SetAuthority` instruction.
-// ///
-// /// This function handles the logic for setting a new authority for a mint or account.
-// /// It validates the accounts and signers, constructs the instruction, and invokes it.
-// ///
-// /// ### Parameters:
-// /// - `accounts`: The accounts required for the instruction.
-// /// - `authority_type`: The type of authority to set.
-// /// - `new_authority`: The optional new authority to set.
-// /// - `signers`: The signers array needed to authorize the transaction.
-// ///
-// /// ### Accounts:
-// /// 0. `[WRITE]` The mint or account to change the authority of.
-// /// 1. `[SIGNER]` The current authority of the mint or account.
-// ///
-// /// ### Returns:
-// /// - `ProgramResult`: Indicates success or failure of the instruction processing.
-// pub fn process_set_authority<'a>(
-//     accounts: &'a [AccountInfo],
-//     authority_type: AuthorityType,
-//     new_authority: Option<&Pubkey>, // Optional new authority
-//     signers: &[Signer],
-// ) -> ProgramResult {
-//     // Destructure the accounts array into individual accounts.
-//     let [account_to_update, current_authority] = accounts else {
-//         // Return an error if there are not enough accounts provided.
-//         return Err(ProgramError::NotEnoughAccountKeys);
-//     };
-
-//     // Ensure the account to update is writable.
-//     if !account_to_update.is_writable() {
-//         return Err(ProgramError::InvalidAccountData);
-//     }
-
-//     // Ensure the current authority account is a signer.
-//     if !current_authority.is_signer() {
-//         return Err(ProgramError::MissingRequiredSignature);
-//     }
-
-//     // Construct the `SetAuthority` instruction.
-//     let set_authority_instruction = SetAuthority {
-//         account: account_to_update,
-//         authority: current_authority,
-//         authority_type,
-//         new_authority,
-//     };
-
-//     // Invoke the instruction with the provided signers.
-//     set_authority_instruction.invoke_signed(signers)?;
-
-//     Ok(())
-// }
\ No newline at end of file
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Extract the authority type, followed by the new authority if one is set.
+    let (authority_type, new_authority) = match data {
+        [authority_type] => (authority_type_from_u8(*authority_type)?, None),
+        [authority_type, ..] if data.len() == 33 => {
+            (authority_type_from_u8(*authority_type)?, Some(read_pubkey(data, 1)?))
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    // Call the `process_set_authority` function to handle the instruction logic.
+    process_set_authority(accounts, authority_type, new_authority.as_ref())
+}
+
+/// Decodes an authority type, using the token program's numbering.
+///
+/// ### Parameters:
+/// - `value`: The encoded authority type.
+///
+/// ### Returns:
+/// - `Result<AuthorityType, ProgramError>`: The authority type, or `InvalidInstructionData`
+///   for unknown values.
+fn authority_type_from_u8(value: u8) -> Result<AuthorityType, ProgramError> {
+    match value {
+        0 => Ok(AuthorityType::MintTokens),
+        1 => Ok(AuthorityType::FreezeAccount),
+        2 => Ok(AuthorityType::AccountOwner),
+        3 => Ok(AuthorityType::CloseAccount),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Processes the `SetAuthority` instruction.
+///
+/// This function handles the logic for setting a new authority for a mint or account.
+/// It validates the accounts and signers, constructs the instruction, and invokes it.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+/// - `authority_type`: The type of authority to set.
+/// - `new_authority`: The optional new authority to set.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The mint or account to change the authority of.
+/// 1. `[SIGNER]` The current authority of the mint or account.
+/// 2. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_set_authority<'a>(
+    accounts: &'a [AccountInfo],
+    authority_type: AuthorityType,
+    new_authority: Option<&Pubkey>, // Optional new authority
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [account_to_update, current_authority, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the account to update is writable.
+    if !account_to_update.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the current authority account is a signer.
+    if !current_authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Construct the `SetAuthority` instruction.
+    let set_authority_instruction = SetAuthority {
+        account: account_to_update,
+        authority: current_authority,
+        authority_type,
+        new_authority,
+    };
+
+    // Invoke the instruction.
+    set_authority_instruction.invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{assert_custom_error, metas, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+    use spl_token::error::TokenError;
+
+    /// Tests that after changing the `AccountOwner` authority, only the new owner can
+    /// transfer out of the account.
+    #[test]
+    fn set_authority_account_owner_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let old_owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+
+        // Encode `[authority_type][new_authority]`, with `AccountOwner` = 2.
+        let mut data = vec![2];
+        data.extend_from_slice(new_owner.as_ref());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                account => (account, false, true),
+                current_authority => (old_owner, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &[
+                (account, token_account(&mollusk, mint, old_owner, 1_000)),
+                (old_owner, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account.clone()),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let updated = result.get_account(&account).unwrap().clone();
+        assert_eq!(
+            spl_token::state::Account::unpack(updated.data()).unwrap().owner,
+            new_owner
+        );
+
+        // Transfer out of the updated account, signed by `owner`.
+        let transfer = |owner: Pubkey| {
+            let instruction =
+                spl_token::instruction::transfer(&spl_token::id(), &account, &recipient, &owner, &[], 100)
+                    .unwrap();
+            let accounts = vec![
+                (account, updated.clone()),
+                (recipient, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (owner, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account.clone()),
+            ];
+            mollusk.process_instruction(&instruction, &accounts)
+        };
+
+        // The new owner can transfer.
+        let result = transfer(new_owner);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The old owner no longer can.
+        let result = transfer(old_owner);
+        assert_custom_error(&result, TokenError::OwnerMismatch as u32);
+    }
+}
@@ -0,0 +1,552 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::{
+    error::ExampleError,
+    guards::{require_key, require_owned_by_program},
+};
+use crate::state::tagged_account::{load_tagged, write_tag, DISCRIMINATOR_LEN};
+
+/// The discriminator tagging a stream state account.
+pub const STREAM_STATE: [u8; DISCRIMINATOR_LEN] = *b"stream\0\0";
+
+/// The size of the stream state:
+/// `[tag: 8][authority: 32][sender: 32][recipient: 32][start: i64][rate: u64][last_claim: i64]`,
+/// little-endian.
+pub const STREAM_STATE_LEN: usize = DISCRIMINATOR_LEN + 3 * 32 + 24;
+
+// The offset of the last claim, the only field written after initialization.
+const LAST_CLAIM_OFFSET: usize = STREAM_STATE_LEN - 8;
+
+/// Discriminator for the instruction recording the schedule of a stream.
+pub const INITIALIZE: u8 = 0;
+
+/// Discriminator for the instruction claiming the vested tokens.
+pub const CLAIM: u8 = 1;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Dispatch on the discriminator in the first byte of the data.
+    match data.split_first() {
+        Some((&INITIALIZE, rest)) => {
+            // Ensure the data length is sufficient for the start timestamp and the rate.
+            if rest.len() < 16 {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            // Extract the start of the stream (unix timestamp) from the data.
+            let start = i64::from_le_bytes(rest[..8].try_into().unwrap());
+
+            // Extract the rate, in tokens per second, from the data.
+            let rate = u64::from_le_bytes(rest[8..16].try_into().unwrap());
+
+            process_initialize_stream(program_id, accounts, start, rate)
+        }
+        Some((&CLAIM, _)) => process_streamed_transfer(program_id, accounts),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// A read-only view over the state of a stream.
+pub struct StreamState<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> StreamState<'a> {
+    /// Creates a view over raw stream state data, validating its length.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if data.len() != STREAM_STATE_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { data })
+    }
+
+    /// The authority of the sender account that funds the stream.
+    pub fn authority(&self) -> &'a Pubkey {
+        self.data[8..40].try_into().unwrap()
+    }
+
+    /// The token account the stream is paid from.
+    pub fn sender(&self) -> &'a Pubkey {
+        self.data[40..72].try_into().unwrap()
+    }
+
+    /// The token account the stream is paid to.
+    pub fn recipient(&self) -> &'a Pubkey {
+        self.data[72..104].try_into().unwrap()
+    }
+
+    /// The unix timestamp the stream starts at.
+    pub fn start(&self) -> i64 {
+        i64::from_le_bytes(self.data[104..112].try_into().unwrap())
+    }
+
+    /// The tokens vested per second.
+    pub fn rate(&self) -> u64 {
+        u64::from_le_bytes(self.data[112..120].try_into().unwrap())
+    }
+
+    /// The unix timestamp of the last claim, or `0`.
+    pub fn last_claim(&self) -> i64 {
+        i64::from_le_bytes(self.data[LAST_CLAIM_OFFSET..].try_into().unwrap())
+    }
+}
+
+/// Computes the amount vested between the last claim and `now`.
+///
+/// Nothing vests before `start`, and a last claim of `0` means nothing was claimed yet.
+///
+/// ### Parameters:
+/// - `start`: The unix timestamp the stream starts at.
+/// - `rate`: The tokens vested per second.
+/// - `last_claim`: The unix timestamp of the last claim, or `0`.
+/// - `now`: The current unix timestamp.
+///
+/// ### Returns:
+/// - `Result<(u64, i64), ProgramError>`: The claimable amount and the new last-claim timestamp.
+pub fn claimable(start: i64, rate: u64, last_claim: i64, now: i64) -> Result<(u64, i64), ProgramError> {
+    // Vesting resumes from the later of the start and the last claim.
+    let from = start.max(last_claim);
+
+    if now <= from {
+        return Ok((0, from));
+    }
+
+    let elapsed = (now - from) as u64;
+    let amount = elapsed
+        .checked_mul(rate)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok((amount, now))
+}
+
+/// Processes the instruction recording the schedule of a stream.
+///
+/// The schedule, the authority and the sender and recipient accounts are written once into
+/// a program-owned state account tagged with [`STREAM_STATE`], so later claims vest against
+/// the schedule set here and pay only the recorded recipient.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the state account.
+/// - `accounts`: The accounts required for the instruction.
+/// - `start`: The unix timestamp the stream starts at.
+/// - `rate`: The tokens vested per second.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The stream state account, owned by this program and still zeroed.
+/// 1. `[SIGNER]` The authority of the sender account funding the stream.
+/// 2. `[]` The sender account.
+/// 3. `[]` The recipient account.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_initialize_stream(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    start: i64, // The unix timestamp the stream starts at.
+    rate: u64,  // The tokens vested per second.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [state_account, authority_account, sender_account, recipient_account] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the state account is owned by this program and writable.
+    require_owned_by_program(state_account, program_id)?;
+    if !state_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // A stream that vests nothing is rejected.
+    if rate == 0 {
+        return Err(ExampleError::ZeroAmount.into());
+    }
+
+    let mut state = state_account.try_borrow_mut_data()?;
+
+    if state.len() != STREAM_STATE_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Tag the state, which fails if the stream has been recorded already.
+    write_tag(&mut state, STREAM_STATE)?;
+
+    state[8..40].copy_from_slice(authority_account.key());
+    state[40..72].copy_from_slice(sender_account.key());
+    state[72..104].copy_from_slice(recipient_account.key());
+    state[104..112].copy_from_slice(&start.to_le_bytes());
+    state[112..120].copy_from_slice(&rate.to_le_bytes());
+    state[LAST_CLAIM_OFFSET..].copy_from_slice(&0_i64.to_le_bytes());
+
+    Ok(())
+}
+
+/// Processes the streamed transfer instruction.
+///
+/// This function reads the current time from the `Clock` sysvar, transfers only the tokens
+/// vested since the last claim under the schedule stored in a program-owned state account,
+/// and records the claim time in that account. The authority, the sender and the recipient
+/// must be the accounts recorded when the stream was initialized.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the state account.
+/// - `accounts`: The accounts involved in the transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The stream state account, owned by this program and tagged with
+///    [`STREAM_STATE`].
+/// 1. `[WRITE]` The sender account recorded in the stream state.
+/// 2. `[WRITE]` The recipient account recorded in the stream state.
+/// 3. `[SIGNER]` The authority recorded in the stream state.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_streamed_transfer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [state_account, sender_account, recipient_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the state account is an initialized stream owned by this program and writable.
+    require_owned_by_program(state_account, program_id)?;
+    load_tagged(state_account, STREAM_STATE)?;
+    if !state_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Compute the amount vested since the last claim and record the new claim time.
+    let amount = {
+        let mut data = state_account.try_borrow_mut_data()?;
+        let state = StreamState::from_bytes(&data)?;
+
+        // Ensure the accounts are the ones recorded for this stream.
+        require_key(authority_account, state.authority())?;
+        require_key(sender_account, state.sender())?;
+        require_key(recipient_account, state.recipient())?;
+
+        let (amount, claimed_at) = claimable(
+            state.start(),
+            state.rate(),
+            state.last_claim(),
+            Clock::get()?.unix_timestamp,
+        )?;
+        data[LAST_CLAIM_OFFSET..].copy_from_slice(&claimed_at.to_le_bytes());
+
+        amount
+    };
+
+    // Nothing has vested since the last claim.
+    if amount == 0 {
+        return Ok(());
+    }
+
+    // Transfer the vested tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{claimable, StreamState, CLAIM, INITIALIZE, STREAM_STATE_LEN};
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests the vesting arithmetic.
+    #[test]
+    fn claimable_test() {
+        // Nothing vests before the start.
+        assert_eq!(claimable(100, 5, 0, 50), Ok((0, 100)));
+
+        // First claim vests from the start.
+        assert_eq!(claimable(100, 5, 0, 110), Ok((50, 110)));
+
+        // Later claims vest from the last claim.
+        assert_eq!(claimable(100, 5, 110, 125), Ok((75, 125)));
+
+        // Claiming twice at the same time yields nothing.
+        assert_eq!(claimable(100, 5, 125, 125), Ok((0, 125)));
+
+        // Overflow is reported instead of wrapping.
+        assert!(claimable(0, u64::MAX, 0, 2).is_err());
+    }
+
+    /// The keys of the accounts of a stream built by [`stream`].
+    struct Keys {
+        state: Pubkey,
+        authority: Pubkey,
+        sender_ta: Pubkey,
+        recipient_ta: Pubkey,
+    }
+
+    /// Builds the accounts of a stream of 5 tokens per second starting at t = 1_000, and the
+    /// instructions initializing it and claiming from it.
+    fn stream(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+    ) -> (Instruction, Instruction, Vec<(Pubkey, AccountSharedData)>, Keys) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let state = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let mut data = vec![INITIALIZE];
+        data.extend_from_slice(&1_000_i64.to_le_bytes());
+        data.extend_from_slice(&5_u64.to_le_bytes());
+
+        let initialize = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                state => (state, false, true),
+                authority => (authority, true, false),
+                sender => (sender_ta, false, false),
+                recipient => (recipient_ta, false, false),
+            ],
+        );
+
+        let claim = Instruction::new_with_bytes(
+            program_id,
+            &[CLAIM],
+            metas![
+                state => (state, false, true),
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (
+                state,
+                AccountSharedData::new(
+                    mollusk.sysvars.rent.minimum_balance(STREAM_STATE_LEN),
+                    STREAM_STATE_LEN,
+                    &program_id,
+                ),
+            ),
+            (sender_ta, token_account(mollusk, mint, authority, 10_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        let keys = Keys {
+            state,
+            authority,
+            sender_ta,
+            recipient_ta,
+        };
+
+        (initialize, claim, accounts, keys)
+    }
+
+    /// Tests that claims at two different times transfer only the newly vested tokens.
+    #[test]
+    fn streamed_transfer_incremental_claims_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (initialize, claim, accounts, keys) = stream(&mut mollusk, program_id);
+
+        let recipient_balance = |accounts: &[(Pubkey, AccountSharedData)]| {
+            let (_, account) = accounts.iter().find(|(key, _)| *key == keys.recipient_ta).unwrap();
+            spl_token::state::Account::unpack(account.data()).unwrap().amount
+        };
+
+        // Record the schedule.
+        let result = mollusk.process_instruction(&initialize, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        let mut accounts = result.resulting_accounts;
+
+        // First claim, 10 seconds in: 50 tokens.
+        mollusk.sysvars.clock.unix_timestamp = 1_010;
+        let result = mollusk.process_instruction(&claim, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        accounts = result.resulting_accounts;
+        assert_eq!(recipient_balance(&accounts), 50);
+
+        // Second claim, 25 seconds in: only the 15 new seconds, 75 tokens.
+        mollusk.sysvars.clock.unix_timestamp = 1_025;
+        let result = mollusk.process_instruction(&claim, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        accounts = result.resulting_accounts;
+        assert_eq!(recipient_balance(&accounts), 125);
+
+        // The state keeps the accounts and the schedule, and records the last claim time.
+        let (_, state_account) = accounts.iter().find(|(key, _)| *key == keys.state).unwrap();
+        assert_eq!(&state_account.data()[..8], b"stream\0\0");
+        let state = StreamState::from_bytes(state_account.data()).unwrap();
+        assert_eq!(state.authority(), keys.authority.as_ref());
+        assert_eq!(state.sender(), keys.sender_ta.as_ref());
+        assert_eq!(state.recipient(), keys.recipient_ta.as_ref());
+        assert_eq!(state.start(), 1_000);
+        assert_eq!(state.rate(), 5);
+        assert_eq!(state.last_claim(), 1_025);
+    }
+
+    /// Tests that a recorded stream cannot be replaced, even by another authority.
+    #[test]
+    fn streamed_transfer_reinitialize_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (initialize, _, accounts, keys) = stream(&mut mollusk, program_id);
+
+        let result = mollusk.process_instruction(&initialize, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        let mut accounts = result.resulting_accounts;
+
+        // The same authority cannot replace the schedule.
+        mollusk.process_and_validate_instruction(
+            &initialize,
+            &accounts,
+            &[Check::err(ProgramError::AccountAlreadyInitialized)],
+        );
+
+        // Another authority cannot redirect the stream to its own recipient.
+        let attacker = Pubkey::new_unique();
+        let attacker_ta = Pubkey::new_unique();
+        let mut data = vec![INITIALIZE];
+        data.extend_from_slice(&0_i64.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        let hijack = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                state => (keys.state, false, true),
+                authority => (attacker, true, false),
+                sender => (keys.sender_ta, false, false),
+                recipient => (attacker_ta, false, false),
+            ],
+        );
+        accounts.push((
+            attacker,
+            AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID),
+        ));
+        accounts.push((attacker_ta, AccountSharedData::default()));
+
+        mollusk.process_and_validate_instruction(
+            &hijack,
+            &accounts,
+            &[Check::err(ProgramError::AccountAlreadyInitialized)],
+        );
+    }
+
+    /// Tests that a claim paying a recipient other than the recorded one is rejected.
+    #[test]
+    fn streamed_transfer_foreign_recipient_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (initialize, mut claim, accounts, _) = stream(&mut mollusk, program_id);
+
+        let result = mollusk.process_instruction(&initialize, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        let mut accounts = result.resulting_accounts;
+
+        // Swap the recorded recipient for another token account of the same mint.
+        let foreign_ta = Pubkey::new_unique();
+        claim.accounts[2].pubkey = foreign_ta;
+        accounts.push((
+            foreign_ta,
+            token_account(
+                &mollusk,
+                Pubkey::new_from_array([0x02; 32]),
+                Pubkey::new_unique(),
+                0,
+            ),
+        ));
+
+        mollusk.sysvars.clock.unix_timestamp = 1_010;
+        mollusk.process_and_validate_instruction(
+            &claim,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+
+    /// Tests that claiming from a stream that was never initialized is rejected.
+    #[test]
+    fn streamed_transfer_uninitialized_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (_, claim, accounts, _) = stream(&mut mollusk, program_id);
+
+        mollusk.process_and_validate_instruction(
+            &claim,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
@@ -8,6 +8,11 @@ use pinocchio::{
 
 use pinocchio_token::instructions::ThawAccount;
 
+use crate::common::{
+    error::ExampleError,
+    token_state::{borrow_token_data, TokenAccount},
+};
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -40,7 +45,8 @@ pub fn process_instruction(
 /// Processes the `ThawAccount` instruction.
 ///
 /// This function handles the logic for thawing a frozen token account. It validates the accounts
-/// and signers, constructs the instruction, and invokes it.
+/// and signers, constructs the instruction, and invokes it. Accounts that are not frozen are
+/// rejected with `ExampleError::AccountNotFrozen` before the CPI.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
@@ -49,6 +55,7 @@ pub fn process_instruction(
 /// 0. `[WRITE]` The token account to be thawed.
 /// 1. `[]` The token mint associated with the account.
 /// 2. `[SIGNER]` The freeze authority for the mint.
+/// 3. `[]` The token program.
 ///
 /// ### Returns:
 /// - `ProgramResult`: Indicates success or failure of the instruction processing.
@@ -56,7 +63,7 @@ pub fn process_thaw_account<'a>(
     accounts: &'a [AccountInfo],
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
-    let [token_account, mint_account, freeze_authority_account] = accounts else {
+    let [token_account, mint_account, freeze_authority_account, _token_program] = accounts else {
         // Return an error if there are not enough accounts provided.
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -71,6 +78,11 @@ pub fn process_thaw_account<'a>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Ensure the token account is frozen, otherwise there is nothing to thaw.
+    if !TokenAccount::from_bytes(&borrow_token_data(token_account)?)?.is_frozen() {
+        return Err(ExampleError::AccountNotFrozen.into());
+    }
+
     // Construct the `ThawAccount` instruction.
     let thaw_account_instruction = ThawAccount {
         account: token_account,
@@ -82,4 +94,101 @@ pub fn process_thaw_account<'a>(
     thaw_account_instruction.invoke()?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::ExampleError;
+    use crate::test_utils::{assert_custom_error, metas, mint_state, packed_account, token_state};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+    use spl_token::state::AccountState;
+
+    /// Builds a thaw of a token account in the given state.
+    fn thaw(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        state: AccountState,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let freeze_authority = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+
+        // Create a mint whose freeze authority is `freeze_authority`.
+        let mint_account = packed_account(
+            mollusk,
+            spl_token::state::Mint {
+                freeze_authority: COption::Some(freeze_authority),
+                ..mint_state(Pubkey::new_unique(), 100)
+            },
+        );
+
+        // Create the token account in the requested state.
+        let token_account = packed_account(
+            mollusk,
+            spl_token::state::Account {
+                state,
+                ..token_state(mint, Pubkey::new_unique(), 100)
+            },
+        );
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[0; 8],
+            metas![
+                account => (account, false, true),
+                mint => (mint, false, false),
+                freeze_authority => (freeze_authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (account, token_account),
+            (mint, mint_account),
+            (freeze_authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, account)
+    }
+
+    /// Tests that a frozen account is thawed.
+    #[test]
+    fn thaw_account_frozen_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, account) = thaw(&mut mollusk, program_id, AccountState::Frozen);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let state =
+            spl_token::state::Account::unpack(result.get_account(&account).unwrap().data()).unwrap();
+        assert_eq!(state.state, AccountState::Initialized);
+    }
+
+    /// Tests that an account that is not frozen is rejected with `AccountNotFrozen`.
+    #[test]
+    fn thaw_account_not_frozen_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = thaw(&mut mollusk, program_id, AccountState::Initialized);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::AccountNotFrozen as u32);
+    }
+}
@@ -0,0 +1,151 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the transfer all instruction.
+    process_transfer_all(accounts)
+}
+
+/// Processes the transfer all instruction.
+///
+/// This function reads the full balance of the source token account and transfers
+/// exactly that amount to the recipient, leaving the source with a zero balance.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_all(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, recipient_account, authority_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Read the full balance of the sender. The borrow is released before the CPI.
+    let amount = {
+        let sender_data = borrow_token_data(sender_account)?;
+        TokenAccount::from_bytes(&sender_data)?.amount()
+    };
+
+    // Transfer the full balance to the recipient.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::token_account;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that the full balance is moved to the recipient.
+    #[test]
+    fn transfer_all_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        // The sender starts with a known balance, the recipient with some tokens already.
+        let minted = 123_456_u64;
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![
+                AccountMeta::new(sender_ta, false),
+                AccountMeta::new(recipient_ta, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (sender_ta, token_account(&mollusk, mint, authority, minted)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 1)),
+                (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let balance = |key: &Pubkey| {
+            spl_token::state::Account::unpack(result.get_account(key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+
+        // The sender is drained and the recipient received everything.
+        assert_eq!(balance(&sender_ta), 0);
+        assert_eq!(balance(&recipient_ta), minted + 1);
+    }
+}
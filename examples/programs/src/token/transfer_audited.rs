@@ -0,0 +1,241 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is valid.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Parse the amount from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the audited transfer instruction.
+    process_transfer_audited(accounts, amount)
+}
+
+/// Formats the audit line logged before and after the transfer.
+///
+/// ### Parameters:
+/// - `stage`: Either `"pre"` or `"post"`.
+/// - `source`: The source balance.
+/// - `destination`: The destination balance.
+///
+/// ### Returns:
+/// - `String`: The line written to the program logs.
+pub fn audit_line(stage: &str, source: u64, destination: u64) -> String {
+    format!("AUDIT {stage} source={source} destination={destination}")
+}
+
+/// Logs the audit line for the balances held by two token accounts.
+///
+/// The log sink is passed in so the output can be captured, as `run_with_log` does in
+/// `common::entry`.
+///
+/// ### Parameters:
+/// - `stage`: Either `"pre"` or `"post"`.
+/// - `source`: The data of the source token account.
+/// - `destination`: The data of the destination token account.
+/// - `log`: The sink receiving the audit line.
+///
+/// ### Returns:
+/// - `ProgramResult`: `InvalidAccountData` if either account is not a token account.
+pub fn log_balances(
+    stage: &str,
+    source: &[u8],
+    destination: &[u8],
+    mut log: impl FnMut(&str),
+) -> ProgramResult {
+    let source = TokenAccount::from_bytes(source)?.amount();
+    let destination = TokenAccount::from_bytes(destination)?.amount();
+
+    log(&audit_line(stage, source, destination));
+
+    Ok(())
+}
+
+/// Processes the audited transfer instruction.
+///
+/// This function logs the source and destination balances, performs the `Transfer` CPI,
+/// and logs the balances again, giving a template for on-chain auditing.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_audited(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, recipient_account, authority_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Log the balances before the transfer.
+    log_balances(
+        "pre",
+        &borrow_token_data(sender_account)?,
+        &borrow_token_data(recipient_account)?,
+        |line| msg!(line),
+    )?;
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    // Log the balances after the transfer.
+    log_balances(
+        "post",
+        &borrow_token_data(sender_account)?,
+        &borrow_token_data(recipient_account)?,
+        |line| msg!(line),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{audit_line, log_balances};
+    use crate::test_utils::token_account;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Tests the audit line format parsed by log consumers.
+    #[test]
+    fn audit_line_test() {
+        assert_eq!(audit_line("pre", 1_000, 0), "AUDIT pre source=1000 destination=0");
+        assert_eq!(audit_line("post", 600, 400), "AUDIT post source=600 destination=400");
+    }
+
+    /// Tests that data which is not a token account logs nothing.
+    #[test]
+    fn log_balances_invalid_account_test() {
+        let mut logs = Vec::new();
+
+        let result = log_balances("pre", &[0; 10], &[0; 10], |line| logs.push(line.to_string()));
+
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+        assert!(logs.is_empty());
+    }
+
+    /// Tests the lines logged for the accounts before and after a transfer of 400 tokens.
+    #[test]
+    fn transfer_audited_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let sender = token_account(&mollusk, mint, authority, 1_000);
+        let recipient = token_account(&mollusk, mint, Pubkey::new_unique(), 0);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &400_u64.to_le_bytes(),
+            vec![
+                AccountMeta::new(sender_ta, false),
+                AccountMeta::new(recipient_ta, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let mut logs = Vec::new();
+        log_balances("pre", sender.data(), recipient.data(), |line| logs.push(line.to_string()))
+            .unwrap();
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (sender_ta, sender),
+                (recipient_ta, recipient),
+                (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // Log the accounts the token program left behind, as the program does after the CPI.
+        log_balances(
+            "post",
+            result.get_account(&sender_ta).unwrap().data(),
+            result.get_account(&recipient_ta).unwrap().data(),
+            |line| logs.push(line.to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            logs,
+            vec![
+                "AUDIT pre source=1000 destination=0".to_string(),
+                "AUDIT post source=600 destination=400".to_string(),
+            ]
+        );
+    }
+}
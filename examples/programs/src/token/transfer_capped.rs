@@ -0,0 +1,470 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::{
+    error::ExampleError,
+    guards::{load_state, require_key, require_owned_by_program},
+};
+use crate::state::tagged_account::{check_tag, load_tagged, write_tag, DISCRIMINATOR_LEN};
+
+/// The discriminator tagging a cap state account.
+pub const CAP_STATE: [u8; DISCRIMINATOR_LEN] = *b"txcap\0\0\0";
+
+/// The size of the cap state: `[tag: 8][authority: 32][sender: 32][max_amount: u64]`.
+pub const CAP_STATE_LEN: usize = DISCRIMINATOR_LEN + 32 + 32 + 8;
+
+/// Discriminator for the instruction setting the cap of a sender account.
+pub const SET_CAP: u8 = 0;
+
+/// Discriminator for the capped transfer instruction.
+pub const TRANSFER: u8 = 1;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Dispatch on the discriminator in the first byte of the data.
+    let (discriminator, rest) = data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    // Both instructions carry a single amount: the cap, or the amount to transfer.
+    let amount = rest
+        .get(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        SET_CAP => process_set_cap(program_id, accounts, amount),
+        TRANSFER => process_transfer_capped(program_id, accounts, amount),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// A read-only view over the cap state of a sender account.
+pub struct CapState<'a> {
+    data: &'a [u8; CAP_STATE_LEN],
+}
+
+impl<'a> CapState<'a> {
+    /// Creates a view over the cap state data.
+    pub fn new(data: &'a [u8; CAP_STATE_LEN]) -> Self {
+        Self { data }
+    }
+
+    /// The authority allowed to change the cap.
+    pub fn authority(&self) -> &'a Pubkey {
+        self.data[8..40].try_into().unwrap()
+    }
+
+    /// The sender account the cap applies to.
+    pub fn sender(&self) -> &'a Pubkey {
+        self.data[40..72].try_into().unwrap()
+    }
+
+    /// The maximum amount allowed in a single transfer.
+    pub fn max_amount(&self) -> u64 {
+        u64::from_le_bytes(self.data[72..80].try_into().unwrap())
+    }
+}
+
+/// Processes the instruction setting the cap of a sender account.
+///
+/// The first call tags the state with [`CAP_STATE`] and records the signer as the authority
+/// and the sender the cap applies to. Later calls must be signed by that authority and only
+/// change the cap.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the cap state account.
+/// - `accounts`: The accounts required for the instruction.
+/// - `max_amount`: The maximum amount allowed in a single transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The cap state account, owned by this program, zeroed or tagged with
+///    [`CAP_STATE`].
+/// 1. `[SIGNER]` The cap authority, recorded on the first call.
+/// 2. `[]` The sender account the cap applies to, recorded on the first call.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_set_cap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_amount: u64, // The maximum amount allowed per transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [state_account, authority_account, sender_account] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the cap state account is owned by this program and writable.
+    require_owned_by_program(state_account, program_id)?;
+    if !state_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut state = state_account.try_borrow_mut_data()?;
+
+    if state.len() != CAP_STATE_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match write_tag(&mut state, CAP_STATE) {
+        // A fresh state records its authority and sender.
+        Ok(()) => {
+            state[8..40].copy_from_slice(authority_account.key());
+            state[40..72].copy_from_slice(sender_account.key());
+        }
+        // An existing cap state only accepts its own authority and sender.
+        Err(ProgramError::AccountAlreadyInitialized) => {
+            check_tag(&state, CAP_STATE)?;
+            let cap = CapState::new((&*state).try_into().unwrap());
+            require_key(authority_account, cap.authority())?;
+            require_key(sender_account, cap.sender())?;
+        }
+        Err(error) => return Err(error),
+    }
+
+    state[72..80].copy_from_slice(&max_amount.to_le_bytes());
+
+    Ok(())
+}
+
+/// Processes the capped transfer instruction.
+///
+/// This function rejects transfers above the cap recorded for the sender account before the
+/// CPI, so a treasury can bound how much leaves it in a single transaction. The cap is read
+/// from a program-owned state account tagged with [`CAP_STATE`], never from the instruction,
+/// so the caller cannot pick their own cap.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the cap state account.
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[]` The cap state account, owned by this program and tagged with [`CAP_STATE`].
+/// 1. `[WRITE]` The sender account recorded in the cap state.
+/// 2. `[WRITE]` The recipient account.
+/// 3. `[SIGNER]` The authority that approves the transfer.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_capped(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [state_account, sender_account, recipient_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Load the cap state kept by this program.
+    let state = load_state::<CAP_STATE_LEN>(state_account, program_id)?;
+    load_tagged(state_account, CAP_STATE)?;
+    let cap = CapState::new(state);
+
+    // Ensure the cap is the one recorded for this sender.
+    require_key(sender_account, cap.sender())?;
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Enforce the per-transfer cap.
+    if amount > cap.max_amount() {
+        return Err(ExampleError::AmountExceedsCap.into());
+    }
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CAP_STATE, CAP_STATE_LEN, SET_CAP, TRANSFER};
+    use crate::common::error::ExampleError;
+    use crate::state::tagged_account::DISCRIMINATOR_LEN;
+    use crate::test_utils::{assert_custom_error, metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Encodes a cap state tagged with `tag`.
+    fn cap_state(
+        tag: [u8; DISCRIMINATOR_LEN],
+        authority: &Pubkey,
+        sender: &Pubkey,
+        max_amount: u64,
+    ) -> Vec<u8> {
+        [
+            &tag[..],
+            authority.as_ref(),
+            sender.as_ref(),
+            &max_amount.to_le_bytes(),
+        ]
+        .concat()
+    }
+
+    /// Creates a cap state account owned by `program_id` holding `data`.
+    fn state_account(mollusk: &Mollusk, program_id: &Pubkey, data: &[u8]) -> AccountSharedData {
+        let mut account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(CAP_STATE_LEN),
+            CAP_STATE_LEN,
+            program_id,
+        );
+        account.set_data_from_slice(data);
+        account
+    }
+
+    /// Builds a capped transfer of `amount` from a sender whose recorded cap is `max_amount`.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        amount: u64,
+        max_amount: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let state = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let mut data = vec![TRANSFER];
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                state => (state, false, false),
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (
+                state,
+                state_account(
+                    mollusk,
+                    &program_id,
+                    &cap_state(CAP_STATE, &authority, &sender_ta, max_amount),
+                ),
+            ),
+            (sender_ta, token_account(mollusk, mint, authority, 10_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, recipient_ta)
+    }
+
+    /// Builds a set-cap instruction signed by `authority` for `sender`.
+    fn set_cap(
+        program_id: Pubkey,
+        state: Pubkey,
+        authority: Pubkey,
+        sender: Pubkey,
+        cap: u64,
+    ) -> Instruction {
+        let mut data = vec![SET_CAP];
+        data.extend_from_slice(&cap.to_le_bytes());
+
+        Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                state => (state, false, true),
+                authority => (authority, true, false),
+                sender => (sender, false, false),
+            ],
+        )
+    }
+
+    /// Tests that the first call records the authority and the sender, and that only that
+    /// authority can change the cap afterwards.
+    #[test]
+    fn set_cap_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let state = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let impostor = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+
+        let accounts = vec![
+            (state, state_account(&mollusk, &program_id, &[0; CAP_STATE_LEN])),
+            (authority, AccountSharedData::default()),
+            (impostor, AccountSharedData::default()),
+            (sender_ta, AccountSharedData::default()),
+        ];
+
+        // The first call records the authority, the sender and the cap.
+        let result = mollusk.process_instruction(
+            &set_cap(program_id, state, authority, sender_ta, 500),
+            &accounts,
+        );
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        assert_eq!(
+            result.get_account(&state).unwrap().data(),
+            cap_state(CAP_STATE, &authority, &sender_ta, 500).as_slice()
+        );
+        let accounts = result.resulting_accounts;
+
+        // The recorded authority can change the cap.
+        let result = mollusk.process_instruction(
+            &set_cap(program_id, state, authority, sender_ta, 1_000),
+            &accounts,
+        );
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        assert_eq!(
+            result.get_account(&state).unwrap().data(),
+            cap_state(CAP_STATE, &authority, &sender_ta, 1_000).as_slice()
+        );
+
+        // Any other signer is rejected.
+        mollusk.process_and_validate_instruction(
+            &set_cap(program_id, state, impostor, sender_ta, u64::MAX),
+            &result.resulting_accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+
+    /// Tests that a transfer of exactly the cap succeeds.
+    #[test]
+    fn transfer_capped_at_cap_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, recipient_ta) = transfer(&mut mollusk, program_id, 500, 500);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let recipient = result.get_account(&recipient_ta).unwrap();
+        assert_eq!(spl_token::state::Account::unpack(recipient.data()).unwrap().amount, 500);
+    }
+
+    /// Tests that a transfer above the cap is rejected before the CPI.
+    #[test]
+    fn transfer_capped_over_cap_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = transfer(&mut mollusk, program_id, 501, 500);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::AmountExceedsCap as u32);
+    }
+
+    /// Tests that the cap recorded for another sender cannot be used.
+    #[test]
+    fn transfer_capped_other_sender_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, mut accounts, _) = transfer(&mut mollusk, program_id, 500, 500);
+
+        // Record the cap for some other sender.
+        let data = cap_state(
+            CAP_STATE,
+            &instruction.accounts[3].pubkey,
+            &Pubkey::new_unique(),
+            u64::MAX,
+        );
+        accounts[0].1.set_data_from_slice(&data);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+
+    /// Tests that a program account without the cap tag cannot stand in for the cap state.
+    #[test]
+    fn transfer_capped_untagged_state_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, mut accounts, _) = transfer(&mut mollusk, program_id, 500, 500);
+
+        let data = cap_state(
+            [0; DISCRIMINATOR_LEN],
+            &instruction.accounts[3].pubkey,
+            &instruction.accounts[1].pubkey,
+            u64::MAX,
+        );
+        accounts[0].1.set_data_from_slice(&data);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
@@ -9,6 +9,8 @@ use pinocchio::{
 
 use pinocchio_token::instructions::TransferChecked;
 
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -47,6 +49,7 @@ pub fn process_instruction(
 ///
 /// This function handles the logic for transferring tokens with a specified amount and decimals.
 /// It validates the accounts and signers, constructs the instruction, and invokes it.
+/// Both token accounts must hold the passed mint, so a mismatch is reported before the CPI.
 ///
 /// ### Parameters:
 /// - `accounts`: The accounts required for the instruction.
@@ -89,6 +92,14 @@ pub fn process_transfer_checked<'a>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Ensure both token accounts hold the passed mint.
+    for token_account in [from_account, to_account] {
+        let data = borrow_token_data(token_account)?;
+        if TokenAccount::from_bytes(&data)?.mint() != mint_account.key() {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
     // Construct the `TransferChecked` instruction.
     let transfer_checked_instruction = TransferChecked {
         from: from_account,
@@ -107,4 +118,55 @@ pub fn process_transfer_checked<'a>(
     transfer_checked_instruction.invoke_signed(&signer)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::Instruction,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that a mint unrelated to the token accounts is rejected before the CPI.
+    #[test]
+    fn transfer_checked_wrong_mint_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let unrelated_mint = Pubkey::new_unique();
+        let (authority, bump) = Pubkey::find_program_address(&[b"authority_account"], &program_id);
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let mut data = 100_u64.to_le_bytes().to_vec();
+        data.push(6);
+        data.push(bump);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                sender => (sender_ta, false, true),
+                mint => (unrelated_mint, false, false),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (sender_ta, token_account(&mollusk, mint, authority, 1_000)),
+                (unrelated_mint, AccountSharedData::new(1_000_000_000, 0, &spl_token::id())),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (authority, AccountSharedData::default()),
+            ],
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::error::ExampleError;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount and the fee.
+    if data.len() < 16 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount for the recipient from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Extract the fee from the data.
+    let fee = unsafe { *(data.as_ptr().add(8) as *const u64) };
+
+    // Process the deduplicated transfer instruction.
+    process_transfer_dedup(accounts, amount, fee)
+}
+
+/// Ensures no two accounts in `accounts` share the same key.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts that must be distinct.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `ExampleError::DuplicateAccount` if a key appears twice.
+pub fn require_distinct(accounts: &[&AccountInfo]) -> Result<(), ProgramError> {
+    for (index, account) in accounts.iter().enumerate() {
+        if accounts[index + 1..]
+            .iter()
+            .any(|other| other.key() == account.key())
+        {
+            return Err(ExampleError::DuplicateAccount.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes a transfer that pays a fee and the recipient from the same source.
+///
+/// The runtime passes a duplicated account as two `AccountInfo`s that share the same data,
+/// so borrowing both mutably fails at runtime and, worse, a source that doubles as the fee
+/// account would pay itself. The source, fee and recipient accounts are therefore checked
+/// to be distinct before any data is borrowed or any CPI is made.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens for the recipient.
+/// - `fee`: The amount of tokens for the fee account.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The fee account.
+/// 2. `[WRITE]` The recipient account.
+/// 3. `[SIGNER]` The authority that approves the transfer.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_dedup(
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens for the recipient.
+    fee: u64,    // The amount of tokens for the fee account.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, fee_account, recipient_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the token accounts are distinct.
+    require_distinct(&[sender_account, fee_account, recipient_account])?;
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Transfer the fee.
+    Transfer {
+        from: sender_account,
+        to: fee_account,
+        authority: authority_account,
+        amount: fee,
+    }
+    .invoke()?;
+
+    // Transfer the amount to the recipient.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Encodes `[amount][fee]`.
+    fn data(amount: u64, fee: u64) -> Vec<u8> {
+        let mut data = amount.to_le_bytes().to_vec();
+        data.extend_from_slice(&fee.to_le_bytes());
+        data
+    }
+
+    /// Tests that distinct accounts receive the amount and the fee.
+    #[test]
+    fn transfer_dedup_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let fee_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data(900, 100),
+            metas![
+                sender => (sender_ta, false, true),
+                fee => (fee_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (sender_ta, token_account(&mollusk, mint, authority, 1_000)),
+                (fee_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let balance = |key: &Pubkey| {
+            spl_token::state::Account::unpack(result.get_account(key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+        assert_eq!(balance(&fee_ta), 100);
+        assert_eq!(balance(&recipient_ta), 900);
+    }
+
+    /// Tests that passing the sender as the fee account is rejected with `DuplicateAccount`.
+    #[test]
+    fn transfer_dedup_duplicate_account_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        // The sender doubles as the fee account.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data(900, 100),
+            metas![
+                sender => (sender_ta, false, true),
+                fee => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (sender_ta, token_account(&mollusk, mint, authority, 1_000)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+            &[Check::err(ProgramError::Custom(4))],
+        );
+    }
+}
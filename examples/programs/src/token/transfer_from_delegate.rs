@@ -0,0 +1,195 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the delegated transfer instruction.
+    process_transfer_from_delegate(accounts, amount)
+}
+
+/// Processes a transfer signed by the source account's delegate.
+///
+/// Before the CPI, the signer is compared to the `delegate` stored in the source token
+/// account, so a wrong signer fails here with `InvalidArgument` instead of with the token
+/// program's more generic owner mismatch error.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The source account.
+/// 1. `[WRITE]` The destination account.
+/// 2. `[SIGNER]` The source account's delegate.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_from_delegate(
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [source_account, destination_account, delegate_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the source and destination accounts are writable.
+    if !source_account.is_writable() || !destination_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the delegate account is a signer.
+    if !delegate_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the signer is the delegate stored in the source account.
+    {
+        let source_data = borrow_token_data(source_account)?;
+        let delegate = TokenAccount::from_bytes(&source_data)?.delegate()?;
+
+        if delegate.as_ref() != Some(delegate_account.key()) {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    // Transfer the tokens on behalf of the owner.
+    Transfer {
+        from: source_account,
+        to: destination_account,
+        authority: delegate_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, packed_account, token_account, token_state};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a delegated transfer of 100 tokens signed by `signer`.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        delegate: Pubkey,
+        signer: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let owner = Pubkey::new_unique();
+        let source_ta = Pubkey::new_unique();
+        let destination_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &100_u64.to_le_bytes(),
+            metas![
+                source => (source_ta, false, true),
+                destination => (destination_ta, false, true),
+                delegate => (signer, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (
+                source_ta,
+                packed_account(
+                    mollusk,
+                    spl_token::state::Account {
+                        delegate: COption::Some(delegate),
+                        delegated_amount: 500,
+                        ..token_state(mint, owner, 1_000)
+                    },
+                ),
+            ),
+            (destination_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (signer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, destination_ta)
+    }
+
+    /// Tests that the stored delegate can transfer from the source account.
+    #[test]
+    fn transfer_from_delegate_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let delegate = Pubkey::new_unique();
+        let (instruction, accounts, destination_ta) =
+            transfer(&mut mollusk, program_id, delegate, delegate);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let destination = result.get_account(&destination_ta).unwrap();
+        assert_eq!(spl_token::state::Account::unpack(destination.data()).unwrap().amount, 100);
+    }
+
+    /// Tests that a signer other than the stored delegate is rejected locally.
+    #[test]
+    fn transfer_from_delegate_wrong_signer_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) =
+            transfer(&mut mollusk, program_id, Pubkey::new_unique(), Pubkey::new_unique());
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
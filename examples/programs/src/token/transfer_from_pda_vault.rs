@@ -0,0 +1,185 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+use crate::pda_signer;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the transfer from PDA vault instruction.
+    process_transfer_from_pda_vault(program_id, accounts, amount)
+}
+
+/// Processes a transfer out of a token account owned by a vault PDA.
+///
+/// The vault address is derived from `[b"vault"]` and this program's id. The passed vault
+/// account must be that PDA and must own the source token account; the transfer is then
+/// signed for with the vault seeds, since a PDA has no private key to sign the transaction.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program the vault is derived from.
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The vault token account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[]` The vault PDA, owner of the vault token account.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_from_pda_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [vault_token_account, recipient_account, vault, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the vault token account and recipient account are writable.
+    if !vault_token_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the vault is the expected PDA.
+    let (address, bump) = find_program_address(&[b"vault"], program_id);
+    if vault.key() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Ensure the vault owns the source token account.
+    if TokenAccount::from_bytes(&borrow_token_data(vault_token_account)?)?.owner() != vault.key() {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Transfer the tokens, signing for the vault.
+    Transfer {
+        from: vault_token_account,
+        to: recipient_account,
+        authority: vault,
+        amount,
+    }
+    .invoke_signed(&pda_signer!(b"vault", &[bump]))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer of 400 tokens out of a 1_000-token account owned by `owner`.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        owner: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let (vault, _) = Pubkey::find_program_address(&[b"vault"], &program_id);
+        let vault_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &400_u64.to_le_bytes(),
+            metas![
+                vault_token_account => (vault_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                vault => (vault, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (vault_ta, token_account(mollusk, mint, owner, 1_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (vault, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, vault_ta, recipient_ta)
+    }
+
+    /// Tests that tokens held by the vault PDA are transferred out with the PDA's signature.
+    #[test]
+    fn transfer_from_pda_vault_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (vault, _) = Pubkey::find_program_address(&[b"vault"], &program_id);
+        let (instruction, accounts, vault_ta, recipient_ta) = transfer(&mut mollusk, program_id, vault);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let balance = |key| {
+            spl_token::state::Account::unpack(result.get_account(&key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+        assert_eq!(balance(vault_ta), 600);
+        assert_eq!(balance(recipient_ta), 400);
+    }
+
+    /// Tests that a token account not owned by the vault is rejected with `IllegalOwner`.
+    #[test]
+    fn transfer_from_pda_vault_wrong_owner_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = transfer(&mut mollusk, program_id, Pubkey::new_unique());
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::IllegalOwner)],
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::slice_invoke,
+    entrypoint,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::common::accounts::split_fixed_and_rest;
+
+/// The maximum number of signers of an SPL token multisig.
+pub const MAX_SIGNERS: usize = 11;
+
+/// The discriminator of the token program's `Transfer` instruction.
+const TRANSFER_DISCRIMINATOR: u8 = 3;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the multisig transfer instruction.
+    process_transfer_multisig(accounts, amount)
+}
+
+/// Processes a `Transfer` whose authority is an SPL token multisig.
+///
+/// The multisig account itself does not sign; instead `M` of its `N` signers are passed as
+/// trailing accounts and forwarded to the token program, which checks them against the
+/// multisig. The number of trailing signers therefore varies between instructions.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[]` The multisig that owns the sender account.
+/// 3. `[]` The token program.
+/// 4. ..4+M. `[SIGNER]` The multisig signers.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_multisig(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    // Separate the fixed accounts from the trailing signers.
+    let ([sender_account, recipient_account, multisig_account, token_program], signers) =
+        split_fixed_and_rest::<4>(accounts)?;
+
+    // Ensure between one and `MAX_SIGNERS` signers were passed.
+    if signers.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    if signers.len() > MAX_SIGNERS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure every trailing account signed.
+    if signers.iter().any(|signer| !signer.is_signer()) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Build the account metas: the fixed accounts followed by the signers.
+    let mut metas = Vec::with_capacity(3 + signers.len());
+    metas.push(AccountMeta::writable(sender_account.key()));
+    metas.push(AccountMeta::writable(recipient_account.key()));
+    metas.push(AccountMeta::readonly(multisig_account.key()));
+    metas.extend(signers.iter().map(|signer| AccountMeta::readonly_signer(signer.key())));
+
+    // Build the instruction data: `[discriminator][amount]`.
+    let mut data = [0u8; 9];
+    data[0] = TRANSFER_DISCRIMINATOR;
+    data[1..].copy_from_slice(&amount.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &metas,
+        data: &data,
+    };
+
+    // Collect the account infos in the same order as the metas.
+    let mut account_infos = Vec::with_capacity(3 + signers.len());
+    account_infos.extend([sender_account, recipient_account, multisig_account]);
+    account_infos.extend(signers);
+
+    // Invoke the token program.
+    slice_invoke(&instruction, &account_infos)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{multisig_account, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Transfers 250 tokens out of a `signers`-of-`signers` multisig account.
+    fn transfer(signers: usize) -> (Mollusk, Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let multisig = Pubkey::new_unique();
+        let signer_keys: Vec<Pubkey> = (0..signers).map(|_| Pubkey::new_unique()).collect();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let mut metas = vec![
+            AccountMeta::new(sender_ta, false),
+            AccountMeta::new(recipient_ta, false),
+            AccountMeta::new_readonly(multisig, false),
+            AccountMeta::new_readonly(token_program, false),
+        ];
+        metas.extend(signer_keys.iter().map(|key| AccountMeta::new_readonly(*key, true)));
+
+        let instruction = Instruction::new_with_bytes(program_id, &250_u64.to_le_bytes(), metas);
+
+        let mut accounts = vec![
+            (sender_ta, token_account(&mollusk, mint, multisig, 1_000)),
+            (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+            (multisig, multisig_account(&mollusk, signers as u8, &signer_keys)),
+            (token_program, token_program_account),
+        ];
+        accounts.extend(signer_keys.iter().map(|key| {
+            (*key, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID))
+        }));
+
+        (mollusk, instruction, accounts, recipient_ta)
+    }
+
+    /// Asserts the transfer succeeds and credits the recipient.
+    fn assert_transferred(signers: usize) {
+        let (mollusk, instruction, accounts, recipient_ta) = transfer(signers);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let recipient = result.get_account(&recipient_ta).unwrap();
+        assert_eq!(spl_token::state::Account::unpack(recipient.data()).unwrap().amount, 250);
+    }
+
+    /// Tests a transfer out of a 1-of-1 multisig.
+    #[test]
+    fn transfer_multisig_one_signer_test() {
+        assert_transferred(1);
+    }
+
+    /// Tests a transfer out of a 3-of-3 multisig.
+    #[test]
+    fn transfer_multisig_three_signers_test() {
+        assert_transferred(3);
+    }
+
+    /// Tests that a trailing account that did not sign is rejected.
+    #[test]
+    fn transfer_multisig_missing_signature_test() {
+        let (mollusk, mut instruction, accounts, _) = transfer(3);
+        instruction.accounts[5].is_signer = false;
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        );
+    }
+
+    /// Tests that the fixed accounts are required.
+    #[test]
+    fn transfer_multisig_not_enough_accounts_test() {
+        let (mollusk, mut instruction, mut accounts, _) = transfer(1);
+        instruction.accounts.truncate(3);
+        accounts.truncate(3);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::NotEnoughAccountKeys)],
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke,
+    entrypoint,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+// The discriminator of the SPL Token `Transfer` instruction.
+const TRANSFER_DISCRIMINATOR: u8 = 3;
+
+// Macro to define the program's entry point.
+//
+// Programs that never touch the heap can go further and replace the default allocator with
+// `pinocchio::no_allocator!()`, turning any accidental allocation into a runtime error.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the allocation-free transfer instruction.
+    process_transfer_no_alloc(accounts, amount)
+}
+
+/// Encodes the SPL Token `Transfer` instruction data on the stack.
+///
+/// ### Parameters:
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Returns:
+/// - `[u8; 9]`: The discriminator followed by the little-endian amount.
+pub fn transfer_instruction_data(amount: u64) -> [u8; 9] {
+    let mut data = [0u8; 9];
+    data[0] = TRANSFER_DISCRIMINATOR;
+    data[1..].copy_from_slice(&amount.to_le_bytes());
+    data
+}
+
+/// Processes a token transfer without allocating on the heap.
+///
+/// The CPI is built by hand from fixed-size stack arrays (account metas, account infos and
+/// instruction data) instead of `Vec`s, which makes this a template for programs that need to
+/// keep their heap usage at zero. It behaves exactly like the `transfer_tokens` example.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_no_alloc(
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, recipient_account, authority_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // The account metas, in the order the token program expects them.
+    let account_metas: [AccountMeta; 3] = [
+        AccountMeta::writable(sender_account.key()),
+        AccountMeta::writable(recipient_account.key()),
+        AccountMeta::readonly_signer(authority_account.key()),
+    ];
+
+    // The instruction data, encoded on the stack.
+    let instruction_data = transfer_instruction_data(amount);
+
+    let instruction = Instruction {
+        program_id: &pinocchio_token::ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    // Invoke the token program with a fixed-size array of account infos.
+    invoke(&instruction, &[sender_account, recipient_account, authority_account])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::transfer_instruction_data;
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that the stack-encoded data matches the SPL Token instruction builder.
+    #[test]
+    fn transfer_instruction_data_test() {
+        let expected = spl_token::instruction::transfer(
+            &spl_token::id(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &[],
+            1_234,
+        )
+        .unwrap()
+        .data;
+
+        assert_eq!(transfer_instruction_data(1_234).to_vec(), expected);
+    }
+
+    /// Tests that the transfer produces the same balances as `transfer_tokens`.
+    #[test]
+    fn transfer_no_alloc_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &1_000_u64.to_le_bytes(),
+            metas![
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (sender_ta, token_account(&mollusk, mint, authority, 1_000_000)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 1_000_000)),
+                (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // The same balances the standard transfer leaves behind.
+        let balance = |key: &Pubkey| {
+            spl_token::state::Account::unpack(result.get_account(key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+        assert_eq!(balance(&sender_ta), 999_000);
+        assert_eq!(balance(&recipient_ta), 1_001_000);
+    }
+}
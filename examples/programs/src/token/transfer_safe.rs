@@ -0,0 +1,179 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::error::ExampleError;
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the safe transfer instruction.
+    process_transfer_safe(accounts, amount)
+}
+
+/// Processes a transfer that checks the source balance up front.
+///
+/// The source token account is loaded before the CPI and the transfer is rejected with
+/// `ExampleError::InsufficientFunds` when it holds fewer than `amount` tokens, giving a
+/// clearer error than the token program's.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_safe(
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, recipient_account, authority_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the sender holds enough tokens.
+    {
+        let sender_data = borrow_token_data(sender_account)?;
+        if amount > TokenAccount::from_bytes(&sender_data)?.amount() {
+            return Err(ExampleError::InsufficientFunds.into());
+        }
+    }
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer of `amount` from a sender holding 1_000 tokens.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        amount: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &amount.to_le_bytes(),
+            metas![
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (sender_ta, token_account(mollusk, mint, authority, 1_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, sender_ta)
+    }
+
+    /// Tests that transferring the exact balance succeeds.
+    #[test]
+    fn transfer_safe_exact_balance_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, sender_ta) = transfer(&mut mollusk, program_id, 1_000);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let sender = result.get_account(&sender_ta).unwrap();
+        assert_eq!(spl_token::state::Account::unpack(sender.data()).unwrap().amount, 0);
+    }
+
+    /// Tests that transferring more than the balance fails with `InsufficientFunds`.
+    #[test]
+    fn transfer_safe_over_balance_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = transfer(&mut mollusk, program_id, 1_001);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::Custom(3))],
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is valid.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Parse the amount from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the sponsored transfer instruction.
+    process_transfer_sponsored(accounts, amount)
+}
+
+/// Processes the sponsored transfer instruction.
+///
+/// This function transfers tokens where the transaction fee payer is a different signer
+/// than the token authority. Both must sign, but only the authority is passed to the
+/// `Transfer` CPI; the fee payer never gains any say over the tokens.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[SIGNER]` The fee payer sponsoring the transaction.
+/// 1. `[WRITE]` The sender account.
+/// 2. `[WRITE]` The recipient account.
+/// 3. `[SIGNER]` The authority that approves the transfer.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_sponsored(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [fee_payer, sender_account, recipient_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure both the fee payer and the authority are signers.
+    if !fee_payer.is_signer() || !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Transfer the tokens, authorized by the token authority only.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::token_account;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests a transfer where the fee payer and the token authority are different keys.
+    #[test]
+    fn transfer_sponsored_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let fee_payer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        assert_ne!(fee_payer, authority);
+
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &250_u64.to_le_bytes(),
+            vec![
+                AccountMeta::new(fee_payer, true),
+                AccountMeta::new(sender_ta, false),
+                AccountMeta::new(recipient_ta, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (fee_payer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (sender_ta, token_account(&mollusk, mint, authority, 1_000)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (authority, AccountSharedData::new(0, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let balance = |key: &Pubkey| {
+            spl_token::state::Account::unpack(result.get_account(key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+        assert_eq!(balance(&sender_ta), 750);
+        assert_eq!(balance(&recipient_ta), 250);
+    }
+}
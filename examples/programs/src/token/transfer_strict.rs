@@ -0,0 +1,185 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::error::ExampleError;
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the strict transfer instruction.
+    process_transfer_strict(accounts, amount)
+}
+
+/// Processes a transfer that checks the destination is not frozen.
+///
+/// The token program rejects transfers into a frozen account with a generic
+/// `AccountFrozen`, which does not say which side is frozen. The recipient is loaded before
+/// the CPI and the transfer is rejected with `ExampleError::DestinationFrozen` instead.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_strict(
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, recipient_account, authority_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the recipient can receive tokens.
+    if TokenAccount::from_bytes(&borrow_token_data(recipient_account)?)?.is_frozen() {
+        return Err(ExampleError::DestinationFrozen.into());
+    }
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::ExampleError;
+    use crate::test_utils::{assert_custom_error, metas, packed_account, token_account, token_state};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+    use spl_token::state::AccountState;
+
+    /// Builds a transfer of 100 tokens into a recipient in the given state.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        recipient_state: AccountState,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &100_u64.to_le_bytes(),
+            metas![
+                sender => (sender, false, true),
+                recipient => (recipient, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (sender, token_account(mollusk, mint, authority, 1_000)),
+            (
+                recipient,
+                packed_account(
+                    mollusk,
+                    spl_token::state::Account {
+                        state: recipient_state,
+                        ..token_state(mint, Pubkey::new_unique(), 0)
+                    },
+                ),
+            ),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, recipient)
+    }
+
+    /// Tests that a transfer into an initialized account succeeds.
+    #[test]
+    fn transfer_strict_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, recipient) =
+            transfer(&mut mollusk, program_id, AccountState::Initialized);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let recipient =
+            spl_token::state::Account::unpack(result.get_account(&recipient).unwrap().data()).unwrap();
+        assert_eq!(recipient.amount, 100);
+    }
+
+    /// Tests that a transfer into a frozen account is rejected with `DestinationFrozen`.
+    #[test]
+    fn transfer_strict_frozen_destination_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = transfer(&mut mollusk, program_id, AccountState::Frozen);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::DestinationFrozen as u32);
+    }
+}
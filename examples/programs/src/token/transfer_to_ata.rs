@@ -0,0 +1,187 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::token_state::require_ata;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the transfer to ATA instruction.
+    process_transfer_to_ata(accounts, amount)
+}
+
+/// Processes a `Transfer` into the recipient's associated token account.
+///
+/// The recipient account must be the canonical associated token account of the recipient
+/// wallet for the mint, so tokens cannot be routed to another account the wallet happens
+/// to own, or to an account the wallet does not own at all.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient's associated token account.
+/// 2. `[]` The recipient wallet.
+/// 3. `[]` The mint.
+/// 4. `[SIGNER]` The authority that approves the transfer.
+/// 5. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_to_ata(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, recipient_ata, recipient_wallet, mint_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the recipient account is the wallet's associated token account.
+    require_ata(recipient_ata.key(), recipient_wallet.key(), mint_account.key())?;
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_ata.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_ata,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::token_state::ASSOCIATED_TOKEN_PROGRAM_ID;
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer of 250 tokens to `recipient`, or to the wallet's ATA if `None`.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        recipient: Option<Pubkey>,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+
+        // Derive the wallet's associated token account off-chain.
+        let (ata, _) = Pubkey::find_program_address(
+            &[wallet.as_ref(), spl_token::id().as_ref(), mint.as_ref()],
+            &Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM_ID),
+        );
+        let recipient_ta = recipient.unwrap_or(ata);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &250_u64.to_le_bytes(),
+            metas![
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                wallet => (wallet, false, false),
+                mint => (mint, false, false),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (sender_ta, token_account(mollusk, mint, authority, 1_000)),
+            (recipient_ta, token_account(mollusk, mint, wallet, 0)),
+            (wallet, AccountSharedData::default()),
+            (mint, AccountSharedData::default()),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, recipient_ta)
+    }
+
+    /// Tests that tokens are transferred into the canonical associated token account.
+    #[test]
+    fn transfer_to_ata_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, recipient_ta) = transfer(&mut mollusk, program_id, None);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let recipient = result.get_account(&recipient_ta).unwrap();
+        assert_eq!(spl_token::state::Account::unpack(recipient.data()).unwrap().amount, 250);
+    }
+
+    /// Tests that a token account of the wallet at another address is rejected.
+    #[test]
+    fn transfer_to_ata_spoofed_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) =
+            transfer(&mut mollusk, program_id, Some(Pubkey::new_unique()));
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
@@ -5,6 +5,12 @@ use pinocchio::{
 
 use pinocchio_token::instructions::Transfer;
 
+use crate::common::accounts::require_roles;
+use crate::common::entry::run;
+use crate::common::ix::read_amount;
+use crate::common::token_state::{require_same_mint, TokenAccount};
+use crate::pda_signer;
+
 // A constant representing the program ID, decoded from a base58 string.
 // const ID: [u8; 32] = five8_const::decode_32_const("11111111111111111111111111111111111111111111");
 
@@ -25,16 +31,17 @@ pub fn process_instruction(
     accounts: &[AccountInfo],
     data: &[u8],
 ) -> ProgramResult {
-    // Ensure the data length is valid.
-    if data.len() < 8 {
-        return Err(ProgramError::InvalidInstructionData);
-    }
+    // Run the instruction, logging any error before it is returned.
+    run(|| {
+        // Parse the amount from the data (big-endian with `be_amounts`).
+        let amount = read_amount(data, 0)?;
 
-    // Parse the amount from the data.
-    let amount = unsafe { *(data.as_ptr() as *const u64) };
+        // Parse the optional bump of a PDA authority following the amount.
+        let bump = data.get(8).map(|bump| [*bump]);
 
-    // Process the transfer instruction.
-    process_transfer(accounts, amount)
+        // Process the transfer instruction.
+        process_transfer(accounts, amount, bump)
+    })
 }
 
 /// Processes the `Transfer` instruction.
@@ -42,14 +49,19 @@ pub fn process_instruction(
 /// This function handles the logic for transferring tokens between accounts. It validates
 /// the accounts, constructs the transfer instruction, and invokes it.
 ///
+/// The authority is either a wallet that signed the transaction or, when `bump` is given and
+/// the authority did not sign, the PDA derived from `[b"authority", bump]`, which this
+/// program signs for.
+///
 /// ### Parameters:
 /// - `accounts`: The accounts involved in the transfer.
 /// - `amount`: The amount of tokens to transfer.
+/// - `bump`: The bump of a PDA authority, if any.
 ///
 /// ### Accounts:
 /// 0. `[WRITE]` The sender account.
 /// 1. `[WRITE]` The recipient account.
-/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 2. `[SIGNER]` The authority that approves the transfer, or the PDA authority.
 /// 3. `[]` The token program.
 ///
 /// ### Returns:
@@ -57,6 +69,7 @@ pub fn process_instruction(
 pub fn process_transfer(
     accounts: &[AccountInfo],
     amount: u64,
+    bump: Option<[u8; 1]>,
 ) -> ProgramResult {
     // Destructure the accounts array into individual accounts.
     let [sender_account, recipient_account, authority_account, token_program] = accounts else {
@@ -64,15 +77,12 @@ pub fn process_transfer(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    // Validate that the sender and recipient accounts are writable.
-    assert!(
-        sender_account.is_writable(),
-        "Sender account is not writable"
-    );
-    assert!(
-        recipient_account.is_writable(),
-        "Recipient account is not writable"
-    );
+    // Validate that the sender and recipient accounts are writable, and that the authority
+    // signed unless the program signs for it as a PDA.
+    require_roles(
+        accounts,
+        &[(false, true), (false, true), (bump.is_none(), false), (false, false)],
+    )?;
 
     // Validate that the sender and recipient accounts are owned by the token program.
     assert_eq!(
@@ -86,8 +96,15 @@ pub fn process_transfer(
         "Recipient account is not owned by the token program"
     );
 
-    // Validate that the authority account is a signer.
-    assert!(authority_account.is_signer(), "Authority is not a signer");
+    // Validate that the sender and recipient accounts hold the same mint.
+    {
+        let sender_data = sender_account.try_borrow_data()?;
+        let recipient_data = recipient_account.try_borrow_data()?;
+        require_same_mint(
+            &TokenAccount::from_bytes(&sender_data)?,
+            &TokenAccount::from_bytes(&recipient_data)?,
+        )?;
+    }
 
     // Construct the `Transfer` instruction.
     let transfer_instruction = Transfer {
@@ -97,19 +114,28 @@ pub fn process_transfer(
         amount,
     };
 
-    // Invoke the transfer instruction.
-    transfer_instruction.invoke()?;
+    match bump {
+        // Sign for a PDA authority.
+        Some(bump) if !authority_account.is_signer() => {
+            transfer_instruction.invoke_signed(&pda_signer!(b"authority", &bump))?;
+        }
+        // Invoke the transfer instruction.
+        _ => transfer_instruction.invoke()?,
+    }
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::common::ix::encode_amount;
+    use crate::test_utils::{metas, token_account, TRANSFER_CU_BASELINE};
     use mollusk_svm::{result::Check, Mollusk};
     use pinocchio_token::state::TokenAccount;
     use solana_sdk::{
         account::{AccountSharedData, ReadableAccount, WritableAccount},
-        instruction::{AccountMeta, Instruction},
+        instruction::Instruction,
+        program_error::ProgramError,
         program_option::COption,
         program_pack::Pack,
         pubkey::Pubkey,
@@ -207,17 +233,17 @@ mod tests {
 
         // Define the transfer amount and construct the instruction data.
         let amount = 1_000_u64;
-        let data = amount.to_le_bytes();
+        let data = encode_amount(amount);
 
         // Construct the transfer instruction.
         let instruction = Instruction::new_with_bytes(
             program_id,
             &data,
-            vec![
-                AccountMeta::new(signer_ta, false),
-                AccountMeta::new(recipient_ta, false),
-                AccountMeta::new(signer, true),
-                AccountMeta::new_readonly(token_program, false),
+            metas![
+                sender => (signer_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (signer, true, true),
+                token_program => (token_program, false, false),
             ],
         );
 
@@ -236,4 +262,224 @@ mod tests {
             "Error while processing instruction",
         );
     }
+
+    /// Tests that swapping the recipient and the authority is rejected.
+    ///
+    /// `transfer_test` passes the accounts in the order documented on `process_transfer`;
+    /// this test keeps that contract honest by showing a reordered list does not succeed.
+    #[test]
+    fn transfer_reordered_accounts_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let signer = Pubkey::new_unique();
+        let signer_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        // The authority is passed where the recipient is documented, and vice versa.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &encode_amount(1_000),
+            metas![
+                sender => (signer_ta, false, true),
+                authority => (signer, true, false),
+                recipient => (recipient_ta, false, true),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        // The read-only authority in the recipient slot fails the role check.
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (signer_ta, token_account(&mollusk, mint, signer, 1_000_000)),
+                (signer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 1_000_000)),
+                (token_program, token_program_account),
+            ],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    /// Tests that `require_roles` rejects a missing signer and a read-only writable account.
+    #[test]
+    fn transfer_missing_roles_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let signer = Pubkey::new_unique();
+        let signer_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let accounts = vec![
+            (signer_ta, token_account(&mollusk, mint, signer, 1_000_000)),
+            (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 1_000_000)),
+            (signer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+        let transfer = |recipient_writable: bool, authority_signer: bool| {
+            Instruction::new_with_bytes(
+                program_id,
+                &encode_amount(1_000),
+                metas![
+                    sender => (signer_ta, false, true),
+                    recipient => (recipient_ta, false, recipient_writable),
+                    authority => (signer, authority_signer, false),
+                    token_program => (token_program, false, false),
+                ],
+            )
+        };
+
+        // The authority did not sign and no bump was given.
+        mollusk.process_and_validate_instruction(
+            &transfer(true, false),
+            &accounts,
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        );
+
+        // The recipient was passed read-only.
+        mollusk.process_and_validate_instruction(
+            &transfer(false, true),
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    /// Tests that the transfer hot path stays within `TRANSFER_CU_BASELINE`.
+    #[test]
+    fn transfer_compute_units_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let signer = Pubkey::new_unique();
+        let signer_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &encode_amount(1_000),
+            metas![
+                sender => (signer_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (signer, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (signer_ta, token_account(&mollusk, mint, signer, 1_000_000)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 1_000_000)),
+                (signer, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        assert!(
+            result.compute_units_consumed <= TRANSFER_CU_BASELINE,
+            "Transfer consumed {} compute units, above the {} baseline",
+            result.compute_units_consumed,
+            TRANSFER_CU_BASELINE,
+        );
+    }
+
+    /// Builds a transfer of 1_000 tokens whose authority is the `[b"authority"]` PDA, which
+    /// does not sign, optionally followed by the PDA's bump.
+    fn pda_transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        with_bump: bool,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let (authority, bump) = Pubkey::find_program_address(&[b"authority"], &program_id);
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let mut data = encode_amount(1_000).to_vec();
+        if with_bump {
+            data.push(bump);
+        }
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, false, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (sender_ta, token_account(mollusk, mint, authority, 1_000_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 1_000_000)),
+            (authority, AccountSharedData::default()),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, sender_ta, recipient_ta)
+    }
+
+    /// Tests that a trailing bump lets the program sign for a PDA authority.
+    #[test]
+    fn transfer_pda_authority_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, sender_ta, recipient_ta) =
+            pda_transfer(&mut mollusk, program_id, true);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let balance = |key| {
+            spl_token::state::Account::unpack(result.get_account(&key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+        assert_eq!(balance(sender_ta), 999_000);
+        assert_eq!(balance(recipient_ta), 1_001_000);
+    }
+
+    /// Tests that, without a bump, an authority that did not sign is rejected.
+    #[test]
+    fn transfer_pda_authority_without_bump_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, ..) = pda_transfer(&mut mollusk, program_id, false);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            result.program_result.is_err(),
+            "A PDA authority without a bump should fail",
+        );
+    }
 }
@@ -0,0 +1,217 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::guards::require_owned_by_program;
+
+/// The size of the tracking state: the little-endian `u64` total transferred so far.
+pub const TRACKED_STATE_LEN: usize = 8;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the tracked transfer instruction.
+    process_transfer_tracked(program_id, accounts, amount)
+}
+
+/// Processes a transfer that keeps a running total of the tokens transferred.
+///
+/// After the transfer succeeds, `amount` is added to the `total_transferred` counter stored
+/// in a program-owned state account. The addition is checked, so a total that would wrap
+/// fails the whole instruction, transfer included.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the state account.
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The tracking state account, owned by this program.
+/// 1. `[WRITE]` The sender account.
+/// 2. `[WRITE]` The recipient account.
+/// 3. `[SIGNER]` The authority of the sender account.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_tracked(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [state_account, sender_account, recipient_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the state account is owned by this program and writable.
+    require_owned_by_program(state_account, program_id)?;
+    if !state_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    // Add the amount to the running total.
+    let mut state = state_account.try_borrow_mut_data()?;
+
+    if state.len() != TRACKED_STATE_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let total = u64::from_le_bytes(
+        state[..TRACKED_STATE_LEN]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+    )
+    .checked_add(amount)
+    .ok_or(ProgramError::ArithmeticOverflow)?;
+    state.copy_from_slice(&total.to_le_bytes());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TRACKED_STATE_LEN;
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer of `amount` tokens with the state account holding `total`.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        amount: u64,
+        total: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let state = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &amount.to_le_bytes(),
+            metas![
+                state => (state, false, true),
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let mut state_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(TRACKED_STATE_LEN),
+            TRACKED_STATE_LEN,
+            &program_id,
+        );
+        state_account.set_data_from_slice(&total.to_le_bytes());
+
+        let accounts = vec![
+            (state, state_account),
+            (sender_ta, token_account(mollusk, mint, authority, 10_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, state)
+    }
+
+    /// Tests that two transfers accumulate their amounts in the state account.
+    #[test]
+    fn transfer_tracked_accumulates_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (mut instruction, mut accounts, state) = transfer(&mut mollusk, program_id, 300, 0);
+
+        // First transfer: 300 tokens.
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+        accounts = result.resulting_accounts;
+
+        // Second transfer: 450 tokens.
+        instruction.data = 450_u64.to_le_bytes().to_vec();
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        assert_eq!(
+            result.get_account(&state).unwrap().data(),
+            &750_u64.to_le_bytes()
+        );
+    }
+
+    /// Tests that a total that would wrap fails with `ArithmeticOverflow`.
+    #[test]
+    fn transfer_tracked_overflow_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = transfer(&mut mollusk, program_id, 1, u64::MAX);
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::ArithmeticOverflow)],
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::{error::ExampleError, guards::require_owned_by_program};
+use crate::state::tagged_account::{load_tagged, DISCRIMINATOR_LEN};
+
+/// The discriminator tagging a whitelist account.
+pub const WHITELIST: [u8; DISCRIMINATOR_LEN] = *b"whitelst";
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Process the whitelisted transfer instruction.
+    process_transfer_whitelisted(program_id, accounts, amount)
+}
+
+/// Ensures `key` is one of the keys of a whitelist.
+///
+/// The keys are stored as consecutive 32-byte keys after the [`WHITELIST`] discriminator,
+/// without a length prefix.
+///
+/// ### Parameters:
+/// - `whitelist`: The whitelist keys, without the discriminator.
+/// - `key`: The key to look up.
+///
+/// ### Returns:
+/// - `Result<(), ProgramError>`: `InvalidAccountData` if the data is not a whole number of
+///   keys, or `ExampleError::RecipientNotWhitelisted` if `key` is not listed.
+pub fn require_whitelisted(whitelist: &[u8], key: &Pubkey) -> Result<(), ProgramError> {
+    if whitelist.len() % 32 != 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if whitelist.chunks_exact(32).any(|listed| listed == key) {
+        Ok(())
+    } else {
+        Err(ExampleError::RecipientNotWhitelisted.into())
+    }
+}
+
+/// Processes a transfer restricted to whitelisted recipients.
+///
+/// The recipient token account must be listed in a whitelist kept in a program-owned
+/// account tagged with [`WHITELIST`], so no other account of this program can stand in
+/// for it. The check runs before the CPI, so a rejected transfer moves no tokens.
+///
+/// ### Parameters:
+/// - `program_id`: The ID of the program that must own the whitelist account.
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+///
+/// ### Accounts:
+/// 0. `[]` The whitelist account, owned by this program and tagged with [`WHITELIST`].
+/// 1. `[WRITE]` The sender account.
+/// 2. `[WRITE]` The recipient account.
+/// 3. `[SIGNER]` The authority of the sender account.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_whitelisted(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64, // The amount of tokens to transfer.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [whitelist_account, sender_account, recipient_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the whitelist is the one kept by this program.
+    require_owned_by_program(whitelist_account, program_id)?;
+    load_tagged(whitelist_account, WHITELIST)?;
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the recipient is whitelisted.
+    require_whitelisted(
+        &whitelist_account.try_borrow_data()?[DISCRIMINATOR_LEN..],
+        recipient_account.key(),
+    )?;
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{require_whitelisted, WHITELIST};
+    use crate::common::error::ExampleError;
+    use crate::state::tagged_account::{write_tag, DISCRIMINATOR_LEN};
+    use crate::test_utils::{assert_custom_error, metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount, WritableAccount},
+        instruction::Instruction,
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer of 300 tokens to `recipient_ta`, with `listed` in a whitelist
+    /// tagged with `tag`.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        recipient_ta: Pubkey,
+        listed: &[Pubkey],
+        tag: [u8; DISCRIMINATOR_LEN],
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let whitelist = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &300_u64.to_le_bytes(),
+            metas![
+                whitelist => (whitelist, false, false),
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let mut data = vec![0; DISCRIMINATOR_LEN];
+        write_tag(&mut data, tag).unwrap();
+        data.extend(listed.iter().flat_map(|key| key.to_bytes()));
+        let mut whitelist_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(data.len()),
+            data.len(),
+            &program_id,
+        );
+        whitelist_account.set_data_from_slice(&data);
+
+        let accounts = vec![
+            (whitelist, whitelist_account),
+            (sender_ta, token_account(mollusk, mint, authority, 10_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts)
+    }
+
+    /// Tests the whitelist lookup.
+    #[test]
+    fn require_whitelisted_test() {
+        let whitelist = [[1; 32], [2; 32]].concat();
+
+        assert_eq!(require_whitelisted(&whitelist, &[2; 32]), Ok(()));
+        assert_eq!(
+            require_whitelisted(&whitelist, &[3; 32]),
+            Err(ExampleError::RecipientNotWhitelisted.into())
+        );
+
+        // A truncated whitelist is rejected instead of being partially read.
+        assert_eq!(
+            require_whitelisted(&whitelist[..40], &[1; 32]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    /// Tests that a transfer to a whitelisted recipient succeeds.
+    #[test]
+    fn transfer_whitelisted_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let recipient_ta = Pubkey::new_unique();
+        let (instruction, accounts) = transfer(
+            &mut mollusk,
+            program_id,
+            recipient_ta,
+            &[Pubkey::new_unique(), recipient_ta],
+            WHITELIST,
+        );
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let recipient =
+            spl_token::state::Account::unpack(result.get_account(&recipient_ta).unwrap().data())
+                .unwrap();
+        assert_eq!(recipient.amount, 300);
+    }
+
+    /// Tests that a transfer to a recipient missing from the whitelist is rejected.
+    #[test]
+    fn transfer_whitelisted_unlisted_recipient_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts) = transfer(
+            &mut mollusk,
+            program_id,
+            Pubkey::new_unique(),
+            &[Pubkey::new_unique()],
+            WHITELIST,
+        );
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::RecipientNotWhitelisted as u32);
+    }
+
+    /// Tests that a program account with another tag cannot stand in for the whitelist.
+    #[test]
+    fn transfer_whitelisted_wrong_tag_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let recipient_ta = Pubkey::new_unique();
+        let (instruction, accounts) = transfer(
+            &mut mollusk,
+            program_id,
+            recipient_ta,
+            &[recipient_ta],
+            *b"vault\0\0\0",
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+}
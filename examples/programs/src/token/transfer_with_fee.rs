@@ -0,0 +1,245 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+// The denominator used for basis point calculations (100% = 10_000 bps).
+const BPS_DENOMINATOR: u64 = 10_000;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount and the fee.
+    if data.len() < 10 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the total amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // Extract the fee, expressed in basis points, from the data.
+    let fee_bps = unsafe { *(data.as_ptr().add(8) as *const u16) };
+
+    // Process the transfer with fee instruction.
+    process_transfer_with_fee(accounts, amount, fee_bps)
+}
+
+/// Splits `amount` into a fee and a net amount.
+///
+/// The fee is `amount * fee_bps / 10_000`, rounded down, so any remainder
+/// always goes to the recipient.
+///
+/// ### Parameters:
+/// - `amount`: The total amount of tokens being transferred.
+/// - `fee_bps`: The fee in basis points (at most 10_000).
+///
+/// ### Returns:
+/// - `Result<(u64, u64), ProgramError>`: The `(fee, net)` pair.
+pub fn split_fee(amount: u64, fee_bps: u16) -> Result<(u64, u64), ProgramError> {
+    // A fee above 100% is never valid.
+    if fee_bps as u64 > BPS_DENOMINATOR {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Compute the fee in 128 bits so large amounts cannot overflow.
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .and_then(|value| value.checked_div(BPS_DENOMINATOR as u128))
+        .ok_or(ProgramError::ArithmeticOverflow)? as u64;
+
+    // The recipient receives whatever is left after the fee.
+    let net = amount
+        .checked_sub(fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok((fee, net))
+}
+
+/// Processes the transfer with fee instruction.
+///
+/// This function splits the amount into a fee and a net amount, then transfers the fee
+/// to the fee account and the remainder to the recipient using two `Transfer` CPIs.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The total amount of tokens to transfer.
+/// - `fee_bps`: The fee in basis points taken from the amount.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The fee account.
+/// 2. `[WRITE]` The recipient account.
+/// 3. `[SIGNER]` The authority that approves the transfer.
+/// 4. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_with_fee(
+    accounts: &[AccountInfo],
+    amount: u64,   // The total amount of tokens to transfer.
+    fee_bps: u16,  // The fee in basis points.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, fee_account, recipient_account, authority_account, _token_program] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender, fee and recipient accounts are writable.
+    if !sender_account.is_writable()
+        || !fee_account.is_writable()
+        || !recipient_account.is_writable()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Split the amount into the fee and the net amount.
+    let (fee, net) = split_fee(amount, fee_bps)?;
+
+    // Transfer the fee to the fee account.
+    Transfer {
+        from: sender_account,
+        to: fee_account,
+        authority: authority_account,
+        amount: fee,
+    }
+    .invoke()?;
+
+    // Transfer the remainder to the recipient.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount: net,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_fee;
+    use crate::test_utils::token_account;
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Tests that the fee is rounded down at odd basis points.
+    #[test]
+    fn split_fee_rounding_test() {
+        // 1_001 * 33 / 10_000 = 3.3033, so the fee rounds down to 3.
+        assert_eq!(split_fee(1_001, 33).unwrap(), (3, 998));
+
+        // 999 * 1 / 10_000 rounds down to zero, the recipient receives everything.
+        assert_eq!(split_fee(999, 1).unwrap(), (0, 999));
+
+        // 10_000 bps takes the full amount as the fee.
+        assert_eq!(split_fee(1_000, 10_000).unwrap(), (1_000, 0));
+
+        // The largest amount cannot overflow the intermediate product.
+        assert_eq!(split_fee(u64::MAX, 10_000).unwrap(), (u64::MAX, 0));
+
+        // Anything above 100% is rejected.
+        assert!(split_fee(1_000, 10_001).is_err());
+    }
+
+    /// Tests that the fee and the net amount reach their destinations.
+    #[test]
+    fn transfer_with_fee_test() {
+        // Define the program ID.
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+
+        // Initialize the token program and Mollusk environment.
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        mollusk_token::token::add_program(&mut mollusk);
+
+        // Define the mint and the authority.
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+
+        // Define the token accounts.
+        let sender_ta = Pubkey::new_unique();
+        let fee_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        // Transfer 1_001 tokens with a 2.5% (250 bps) fee.
+        let amount = 1_001_u64;
+        let fee_bps = 250_u16;
+        let mut data = amount.to_le_bytes().to_vec();
+        data.extend_from_slice(&fee_bps.to_le_bytes());
+
+        // Construct the instruction.
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(sender_ta, false),
+                AccountMeta::new(fee_ta, false),
+                AccountMeta::new(recipient_ta, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        // Process the instruction.
+        let result = mollusk.process_instruction(
+            &instruction,
+            &vec![
+                (sender_ta, token_account(&mollusk, mint, authority, 10_000)),
+                (fee_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (recipient_ta, token_account(&mollusk, mint, Pubkey::new_unique(), 0)),
+                (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        // Read back the resulting token balances.
+        let balance = |key: &Pubkey| {
+            spl_token::state::Account::unpack(result.get_account(key).unwrap().data())
+                .unwrap()
+                .amount
+        };
+
+        // 1_001 * 250 / 10_000 = 25.025, so the fee is 25 and the recipient gets 976.
+        assert_eq!(balance(&fee_ta), 25);
+        assert_eq!(balance(&recipient_ta), 976);
+        assert_eq!(balance(&sender_ta), 10_000 - 1_001);
+    }
+}
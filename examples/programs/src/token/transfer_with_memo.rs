@@ -0,0 +1,208 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke,
+    entrypoint,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+/// The SPL Memo program (v2).
+pub const MEMO_PROGRAM_ID: Pubkey =
+    five8_const::decode_32_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount.
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+
+    // The rest of the data, if any, is the memo.
+    let memo = &data[8..];
+
+    // Process the transfer with memo instruction.
+    process_transfer_with_memo(accounts, amount, memo)
+}
+
+/// Processes a `Transfer` preceded by an optional memo.
+///
+/// When the memo program is passed and the memo is not empty, the memo is logged by the
+/// Memo program, signed by the transfer authority, before the tokens move. Without either,
+/// this is a plain transfer.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+/// - `memo`: The UTF-8 memo, possibly empty.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 3. `[]` The token program.
+/// 4. `[]` (Optional) The memo program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_with_memo(
+    accounts: &[AccountInfo],
+    amount: u64,
+    memo: &[u8],
+) -> ProgramResult {
+    // Destructure the accounts array into the fixed accounts and the optional memo program.
+    let [sender_account, recipient_account, authority_account, _token_program, rest @ ..] =
+        accounts
+    else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Log the memo first, so the transfer is never recorded without it.
+    if let (Some(memo_program), false) = (rest.first(), memo.is_empty()) {
+        // Ensure the memo goes to the actual Memo program.
+        if memo_program.key() != &MEMO_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        invoke(
+            &Instruction {
+                program_id: &MEMO_PROGRAM_ID,
+                accounts: &[AccountMeta::readonly_signer(authority_account.key())],
+                data: memo,
+            },
+            &[authority_account],
+        )?;
+    }
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{metas, token_account};
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer of 250 tokens carrying `memo`, without the memo program account.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        memo: &[u8],
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender_ta = Pubkey::new_unique();
+        let recipient_ta = Pubkey::new_unique();
+
+        let mut data = 250_u64.to_le_bytes().to_vec();
+        data.extend_from_slice(memo);
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                sender => (sender_ta, false, true),
+                recipient => (recipient_ta, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (sender_ta, token_account(mollusk, mint, authority, 1_000)),
+            (recipient_ta, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, recipient_ta)
+    }
+
+    /// Tests that the transfer completes when no memo program is passed.
+    #[test]
+    fn transfer_without_memo_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, recipient_ta) = transfer(&mut mollusk, program_id, b"invoice 42");
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let recipient = result.get_account(&recipient_ta).unwrap();
+        assert_eq!(spl_token::state::Account::unpack(recipient.data()).unwrap().amount, 250);
+    }
+
+    /// Tests that a memo addressed to another program is rejected before any CPI.
+    #[test]
+    fn transfer_with_memo_wrong_program_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (mut instruction, mut accounts, _) = transfer(&mut mollusk, program_id, b"invoice 42");
+
+        let spoofed_memo_program = Pubkey::new_unique();
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(spoofed_memo_program, false));
+        accounts.push((spoofed_memo_program, AccountSharedData::default()));
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::IncorrectProgramId)],
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use pinocchio_token::instructions::Transfer;
+
+use crate::common::error::ExampleError;
+use crate::common::token_state::{borrow_token_data, TokenAccount};
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Ensure the data length is sufficient for the amount and the reserve.
+    if data.len() < 16 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Extract the amount to transfer and the reserve to keep from the data.
+    let amount = unsafe { *(data.as_ptr() as *const u64) };
+    let reserve = unsafe { *(data.as_ptr().add(8) as *const u64) };
+
+    // Process the transfer with reserve instruction.
+    process_transfer_with_reserve(accounts, amount, reserve)
+}
+
+/// Processes a transfer that keeps a minimum balance in the source account.
+///
+/// The source token account is loaded before the CPI, and the transfer is rejected with
+/// `ExampleError::ReserveViolated` when the balance left after it would drop below
+/// `reserve`, or with `ExampleError::InsufficientFunds` when the source holds fewer than
+/// `amount` tokens.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts involved in the transfer.
+/// - `amount`: The amount of tokens to transfer.
+/// - `reserve`: The minimum balance the source must keep.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` The sender account.
+/// 1. `[WRITE]` The recipient account.
+/// 2. `[SIGNER]` The authority that approves the transfer.
+/// 3. `[]` The token program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_transfer_with_reserve(
+    accounts: &[AccountInfo],
+    amount: u64,  // The amount of tokens to transfer.
+    reserve: u64, // The minimum balance the source must keep.
+) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [sender_account, recipient_account, authority_account, _token_program] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Ensure the sender and recipient accounts are writable.
+    if !sender_account.is_writable() || !recipient_account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the authority account is a signer.
+    if !authority_account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the sender keeps at least the reserve after the transfer.
+    {
+        let sender_data = borrow_token_data(sender_account)?;
+        let remaining = TokenAccount::from_bytes(&sender_data)?
+            .amount()
+            .checked_sub(amount)
+            .ok_or(ExampleError::InsufficientFunds)?;
+
+        if remaining < reserve {
+            return Err(ExampleError::ReserveViolated.into());
+        }
+    }
+
+    // Transfer the tokens.
+    Transfer {
+        from: sender_account,
+        to: recipient_account,
+        authority: authority_account,
+        amount,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::ExampleError;
+    use crate::test_utils::{assert_custom_error, metas, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::Instruction,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    };
+
+    /// Builds a transfer of `amount` tokens keeping `reserve`, out of a 1_000-token account.
+    fn transfer(
+        mollusk: &mut Mollusk,
+        program_id: Pubkey,
+        amount: u64,
+        reserve: u64,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>, Pubkey) {
+        let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+        mollusk_token::token::add_program(mollusk);
+
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let authority = Pubkey::new_unique();
+        let sender = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+
+        let mut data = amount.to_le_bytes().to_vec();
+        data.extend_from_slice(&reserve.to_le_bytes());
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            metas![
+                sender => (sender, false, true),
+                recipient => (recipient, false, true),
+                authority => (authority, true, false),
+                token_program => (token_program, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (sender, token_account(mollusk, mint, authority, 1_000)),
+            (recipient, token_account(mollusk, mint, Pubkey::new_unique(), 0)),
+            (authority, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+            (token_program, token_program_account),
+        ];
+
+        (instruction, accounts, sender)
+    }
+
+    /// Tests that a transfer leaving exactly the reserve succeeds.
+    #[test]
+    fn transfer_with_reserve_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, sender) = transfer(&mut mollusk, program_id, 700, 300);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+
+        let sender =
+            spl_token::state::Account::unpack(result.get_account(&sender).unwrap().data()).unwrap();
+        assert_eq!(sender.amount, 300);
+    }
+
+    /// Tests that a transfer dipping below the reserve is rejected with `ReserveViolated`.
+    #[test]
+    fn transfer_with_reserve_violated_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = transfer(&mut mollusk, program_id, 701, 300);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::ReserveViolated as u32);
+    }
+
+    /// Tests that a transfer above the balance is rejected with `InsufficientFunds`.
+    #[test]
+    fn transfer_with_reserve_insufficient_funds_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts, _) = transfer(&mut mollusk, program_id, 1_001, 0);
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::InsufficientFunds as u32);
+    }
+}
@@ -0,0 +1,138 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    entrypoint,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::common::{
+    error::ExampleError,
+    token_state::{borrow_token_data, TokenAccount},
+};
+
+use super::read_external_mint::read_mint;
+
+// Macro to define the program's entry point.
+entrypoint!(process_instruction);
+
+/// Entry point for the program. This function is called when the program is invoked.
+///
+/// ### Parameters:
+/// - `_program_id`: The ID of the program being executed.
+/// - `accounts`: The accounts passed to the program.
+/// - `_data`: Additional data passed to the program.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the program execution.
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    // Process the validate mint consistency instruction.
+    process_validate_mint_consistency(accounts)
+}
+
+/// Processes the validate mint consistency instruction.
+///
+/// Amounts held by a token account are only meaningful with the decimals of its own mint,
+/// so a mint and a token account passed together must belong to each other. Both accounts
+/// are checked to be owned by the token program, the token account's mint field must equal
+/// the mint key, and the mint's decimals are then logged.
+///
+/// ### Parameters:
+/// - `accounts`: The accounts required for the instruction.
+///
+/// ### Accounts:
+/// 0. `[]` The mint account.
+/// 1. `[]` A token account of the mint.
+///
+/// ### Returns:
+/// - `ProgramResult`: Indicates success or failure of the instruction processing.
+pub fn process_validate_mint_consistency(accounts: &[AccountInfo]) -> ProgramResult {
+    // Destructure the accounts array into individual accounts.
+    let [mint_account, token_account] = accounts else {
+        // Return an error if there are not enough accounts provided.
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Read the decimals, checking the mint is an initialized mint owned by the token program.
+    let (_, decimals) = read_mint(&borrow_token_data(mint_account)?)?;
+
+    // Ensure the token account belongs to the mint.
+    let token_data = borrow_token_data(token_account)?;
+    if TokenAccount::from_bytes(&token_data)?.mint() != mint_account.key() {
+        return Err(ExampleError::MintMismatch.into());
+    }
+
+    msg!(&format!("decimals={decimals}"));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::ExampleError;
+    use crate::test_utils::{assert_custom_error, metas, mint_account, token_account};
+    use mollusk_svm::Mollusk;
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::Instruction,
+        pubkey::Pubkey,
+    };
+
+    /// Builds the validation of `mint` against a token account of `account_mint`.
+    fn validate(
+        mollusk: &Mollusk,
+        program_id: Pubkey,
+        account_mint: Pubkey,
+    ) -> (Instruction, Vec<(Pubkey, AccountSharedData)>) {
+        let mint = Pubkey::new_from_array([0x02; 32]);
+        let account = Pubkey::new_unique();
+
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[],
+            metas![
+                mint => (mint, false, false),
+                account => (account, false, false),
+            ],
+        );
+
+        let accounts = vec![
+            (mint, mint_account(mollusk, Pubkey::new_unique(), 100)),
+            (account, token_account(mollusk, account_mint, Pubkey::new_unique(), 100)),
+        ];
+
+        (instruction, accounts)
+    }
+
+    /// Tests that a token account of the mint is accepted.
+    #[test]
+    fn validate_mint_consistency_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts) =
+            validate(&mollusk, program_id, Pubkey::new_from_array([0x02; 32]));
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "Error while processing instruction",
+        );
+    }
+
+    /// Tests that a token account of another mint is rejected with `MintMismatch`.
+    #[test]
+    fn validate_mint_consistency_mismatch_test() {
+        let program_id = Pubkey::new_from_array([0x01; 32]);
+        let mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+        let (instruction, accounts) = validate(&mollusk, program_id, Pubkey::new_unique());
+
+        let result = mollusk.process_instruction(&instruction, &accounts);
+        assert_custom_error(&result, ExampleError::MintMismatch as u32);
+    }
+}
@@ -0,0 +1,339 @@
+//! Compute-unit benchmarks of the instruction processors.
+//!
+//! Each case runs one successful instruction through Mollusk against the program built in
+//! `../target/deploy/programs`, and the consumed compute units are printed as a table. Run
+//! with `cargo test --test benchmarks -- --nocapture` to see it.
+//!
+//! `transfer` and `mint_to` are the hot paths of most token programs, so they are also held
+//! below the baselines documented in `src/test_utils.rs`, which the unit tests share. Raise a
+//! baseline only together with the change that justifies it.
+//!
+//! ### Skipped processors:
+//! The cases cover the plain SPL Token wrappers. The other examples put checks or state in
+//! front of one of these CPIs, so their cost is the benchmarked CPI plus their own checks,
+//! which their unit tests exercise. They are deliberately left out:
+//! - Variants of `transfer`: `transfer_all`, `transfer_audited`, `transfer_capped`,
+//!   `transfer_checked`, `transfer_dedup`, `transfer_from_delegate`, `transfer_from_pda_vault`,
+//!   `transfer_multisig`, `transfer_no_alloc`, `transfer_safe`, `transfer_sponsored`,
+//!   `transfer_strict`, `transfer_to_ata`, `transfer_tracked`, `transfer_whitelisted`,
+//!   `transfer_with_fee`, `transfer_with_memo`, `transfer_with_receipt`,
+//!   `transfer_with_reserve`, `streamed_transfer`, `dispatcher` and `pay_combined`.
+//! - Variants of `mint_to`: `mint_to_batch`, `mint_to_derive`, `mint_to_indexed`,
+//!   `mint_to_logged`, `mint_to_namespaced`, `mint_to_rate_limited`, `mint_to_strict_bump`,
+//!   `mint_to_weighted` and `init_and_mint`.
+//! - Other token wrappers and readers: `approve_checked`, `burn`, `close_account`,
+//!   `close_mint`, `close_to_treasury`, `create_ata_idempotent`, `freeze_account`,
+//!   `freeze_batch`, `initialize_account`, `initialize_account3`, `initialize_mint`,
+//!   `read_cpi_return`, `read_external_mint`, `set_authority`, `sync_native` and
+//!   `validate_mint_consistency`.
+//! - System program examples, which are outside the token hot paths tracked here: all of
+//!   `src/system`.
+
+use mollusk_svm::Mollusk;
+use programs::common::ix::encode_amount;
+use solana_sdk::{
+    account::AccountSharedData, instruction::Instruction, program_option::COption,
+    pubkey::Pubkey,
+};
+use spl_token::state::AccountState;
+
+// The fixtures of the unit tests, shared rather than copied.
+#[allow(dead_code, unused_imports)]
+#[path = "../src/test_utils.rs"]
+mod test_utils;
+
+use test_utils::{
+    metas, mint_account, mint_state, packed_account, token_account, token_state,
+    MINT_TO_CU_BASELINE, TRANSFER_CU_BASELINE,
+};
+
+/// A single instruction to benchmark.
+struct Case {
+    /// The name printed in the table.
+    name: &'static str,
+    /// The instruction to process.
+    instruction: Instruction,
+    /// The accounts the instruction reads and writes.
+    accounts: Vec<(Pubkey, AccountSharedData)>,
+}
+
+/// Encodes `[amount: 8]` followed by `suffix`, such as the decimals or a bump.
+fn amount_data(amount: u64, suffix: &[u8]) -> Vec<u8> {
+    let mut data = encode_amount(amount).to_vec();
+    data.extend_from_slice(suffix);
+    data
+}
+
+/// Builds the benchmark cases, one per instruction.
+fn cases(mollusk: &Mollusk, program_id: Pubkey) -> Vec<Case> {
+    let (token_program, token_program_account) = mollusk_token::token::keyed_account();
+    let mint = Pubkey::new_from_array([0x02; 32]);
+    let wallet = Pubkey::new_unique();
+
+    // `transfer`: the wallet signs for its own token account.
+    let transfer = {
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        Case {
+            name: "transfer",
+            instruction: Instruction::new_with_bytes(
+                program_id,
+                &amount_data(1_000, &[]),
+                metas![
+                    sender => (source, false, true),
+                    recipient => (destination, false, true),
+                    authority => (wallet, true, false),
+                    token_program => (token_program, false, false),
+                ],
+            ),
+            accounts: vec![
+                (source, token_account(mollusk, mint, wallet, 1_000_000)),
+                (destination, token_account(mollusk, mint, Pubkey::new_unique(), 1_000_000)),
+                (wallet, AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID)),
+                (token_program, token_program_account.clone()),
+            ],
+        }
+    };
+
+    // `mint_to`: the program signs for the `[b"mint_authority"]` PDA.
+    let mint_to = {
+        let (authority, bump) = Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let destination = Pubkey::new_unique();
+
+        Case {
+            name: "mint_to",
+            instruction: Instruction::new_with_bytes(
+                program_id,
+                &amount_data(1_000, &[bump]),
+                metas![
+                    mint => (mint, false, true),
+                    destination => (destination, false, true),
+                    mint_authority => (authority, true, false),
+                    token_program => (token_program, false, false),
+                ],
+            ),
+            accounts: vec![
+                (mint, mint_account(mollusk, authority, 1_000_000)),
+                (destination, token_account(mollusk, mint, wallet, 1_000_000)),
+                (authority, AccountSharedData::default()),
+                (token_program, token_program_account.clone()),
+            ],
+        }
+    };
+
+    // `mint_to_checked`: as `mint_to`, with the mint's decimals.
+    let mint_to_checked = {
+        let (authority, bump) = Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+        let destination = Pubkey::new_unique();
+
+        Case {
+            name: "mint_to_checked",
+            instruction: Instruction::new_with_bytes(
+                program_id,
+                &amount_data(1_000, &[6, bump]),
+                metas![
+                    mint => (mint, false, true),
+                    destination => (destination, false, true),
+                    mint_authority => (authority, true, false),
+                    token_program => (token_program, false, false),
+                ],
+            ),
+            accounts: vec![
+                (mint, mint_account(mollusk, authority, 1_000_000)),
+                (destination, token_account(mollusk, mint, wallet, 1_000_000)),
+                (authority, AccountSharedData::default()),
+                (token_program, token_program_account.clone()),
+            ],
+        }
+    };
+
+    // `approve`: the program signs for the `[b"authority_account"]` PDA owning the source.
+    let approve = {
+        let (authority, bump) =
+            Pubkey::find_program_address(&[b"authority_account"], &program_id);
+        let source = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        Case {
+            name: "approve",
+            instruction: Instruction::new_with_bytes(
+                program_id,
+                &amount_data(1_000, &[bump]),
+                metas![
+                    source => (source, false, true),
+                    delegate => (delegate, false, false),
+                    authority => (authority, true, false),
+                    token_program => (token_program, false, false),
+                ],
+            ),
+            accounts: vec![
+                (source, token_account(mollusk, mint, authority, 1_000_000)),
+                (delegate, AccountSharedData::default()),
+                (authority, AccountSharedData::default()),
+                (token_program, token_program_account.clone()),
+            ],
+        }
+    };
+
+    // `revoke`: the program signs for the `[b"owner_account"]` PDA owning the source, which
+    // has a delegate to remove.
+    let revoke = {
+        let (owner, bump) = Pubkey::find_program_address(&[b"owner_account"], &program_id);
+        let source = Pubkey::new_unique();
+
+        let mut data = vec![bump];
+        data.resize(8, 0);
+
+        Case {
+            name: "revoke",
+            instruction: Instruction::new_with_bytes(
+                program_id,
+                &data,
+                metas![
+                    source => (source, false, true),
+                    owner => (owner, true, false),
+                    token_program => (token_program, false, false),
+                ],
+            ),
+            accounts: vec![
+                (
+                    source,
+                    packed_account(
+                        mollusk,
+                        spl_token::state::Account {
+                            delegate: COption::Some(Pubkey::new_unique()),
+                            delegated_amount: 1_000,
+                            ..token_state(mint, owner, 1_000_000)
+                        },
+                    ),
+                ),
+                (owner, AccountSharedData::default()),
+                (token_program, token_program_account.clone()),
+            ],
+        }
+    };
+
+    // `burn_checked`: the program signs for the `[b"authority_account"]` PDA owning the
+    // account.
+    let burn_checked = {
+        let (authority, bump) =
+            Pubkey::find_program_address(&[b"authority_account"], &program_id);
+        let account = Pubkey::new_unique();
+
+        Case {
+            name: "burn_checked",
+            instruction: Instruction::new_with_bytes(
+                program_id,
+                &amount_data(1_000, &[6, bump]),
+                metas![
+                    account => (account, false, true),
+                    mint => (mint, false, true),
+                    authority => (authority, true, false),
+                    token_program => (token_program, false, false),
+                ],
+            ),
+            accounts: vec![
+                (account, token_account(mollusk, mint, authority, 1_000_000)),
+                (mint, mint_account(mollusk, Pubkey::new_unique(), 1_000_000)),
+                (authority, AccountSharedData::default()),
+                (token_program, token_program_account.clone()),
+            ],
+        }
+    };
+
+    // `thaw_account`: the mint's freeze authority signs for a frozen account.
+    let thaw_account = {
+        let freeze_authority = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+
+        Case {
+            name: "thaw_account",
+            instruction: Instruction::new_with_bytes(
+                program_id,
+                &[0; 8],
+                metas![
+                    account => (account, false, true),
+                    mint => (mint, false, false),
+                    freeze_authority => (freeze_authority, true, false),
+                    token_program => (token_program, false, false),
+                ],
+            ),
+            accounts: vec![
+                (
+                    account,
+                    packed_account(
+                        mollusk,
+                        spl_token::state::Account {
+                            state: AccountState::Frozen,
+                            ..token_state(mint, wallet, 1_000_000)
+                        },
+                    ),
+                ),
+                (
+                    mint,
+                    packed_account(
+                        mollusk,
+                        spl_token::state::Mint {
+                            freeze_authority: COption::Some(freeze_authority),
+                            ..mint_state(Pubkey::new_unique(), 1_000_000)
+                        },
+                    ),
+                ),
+                (
+                    freeze_authority,
+                    AccountSharedData::new(1_000_000_000, 0, &solana_sdk::system_program::ID),
+                ),
+                (token_program, token_program_account),
+            ],
+        }
+    };
+
+    vec![
+        transfer,
+        mint_to,
+        mint_to_checked,
+        approve,
+        revoke,
+        burn_checked,
+        thaw_account,
+    ]
+}
+
+/// Runs every case, prints the compute units in a table and checks the baselines.
+#[test]
+fn compute_units_benchmark() {
+    let program_id = Pubkey::new_from_array([0x01; 32]);
+    let mut mollusk = Mollusk::new(&program_id, "../target/deploy/programs");
+    mollusk_token::token::add_program(&mut mollusk);
+
+    println!("| {:<15} | {:>13} |", "instruction", "compute units");
+    println!("|{:-<17}|{:->15}|", "", "");
+
+    for case in cases(&mollusk, program_id) {
+        let result = mollusk.process_instruction(&case.instruction, &case.accounts);
+
+        assert!(
+            !result.program_result.is_err(),
+            "{} failed: {:?}",
+            case.name,
+            result.program_result,
+        );
+
+        println!("| {:<15} | {:>13} |", case.name, result.compute_units_consumed);
+
+        let baseline = match case.name {
+            "transfer" => TRANSFER_CU_BASELINE,
+            "mint_to" => MINT_TO_CU_BASELINE,
+            _ => continue,
+        };
+
+        assert!(
+            result.compute_units_consumed <= baseline,
+            "{} consumed {} compute units, above its {} baseline",
+            case.name,
+            result.compute_units_consumed,
+            baseline,
+        );
+    }
+}